@@ -0,0 +1,119 @@
+// Terra Lock ホストページ連携API
+//
+// mq_js_bundle.js の後、load() の前に読み込むこと。
+// 使用例:
+//   TerraLock.onGameOver(function (stats) { console.log(stats.score); });
+//   TerraLock.setConfig({ lockRadius: 120, maxTargets: 4 });
+//   TerraLock.start(12345);
+
+var TerraLock = (function () {
+    // Rust側 GameConfig::KEYS と同じ順序
+    var CONFIG_KEYS = [
+        "lockRadius",
+        "maxTargets",
+        "longPressTime",
+        "normalLaserSpeed",
+        "homingInitialSpeed",
+        "homingAcceleration",
+        "homingMaxSpeed",
+        "difficultyStepTime",
+        "lockTime",
+        "lockOrder",
+        "lockMode",
+        "lockShape",
+        "maxLocksPerTarget",
+        "lockStackTime",
+        "homingTrajectory",
+        "homingTurnRate",
+        "homingTargetLoss",
+        "fireMode",
+        "fireInterval",
+        "lockEnergyDrain",
+        "lockEnergyPerLock",
+        "lockEnergyRegen",
+        "lockEnergyPerKill",
+        "bombStock",
+    ];
+
+    // Rust側 GameState::id と同じ順序
+    var STATE_NAMES = ["title", "playing", "gameover", "demo", "shipselect"];
+
+    var callbacks = {
+        gameOver: [],
+        locksFired: [],
+        stateChange: [],
+    };
+
+    function dispatch(name, value) {
+        callbacks[name].forEach(function (callback) {
+            try {
+                callback(value);
+            } catch (err) {
+                console.error(err);
+            }
+        });
+    }
+
+    function exports() {
+        if (typeof wasm_exports === "undefined" || wasm_exports === undefined) {
+            console.warn("Terra Lock is not loaded yet");
+            return null;
+        }
+        return wasm_exports;
+    }
+
+    miniquad_add_plugin({
+        name: "terra_lock",
+        // Rust側 API_VERSION を miniquad の u32_to_semver で変換した値
+        version: "0.0.1",
+        register_plugin: function (importObject) {
+            importObject.env.terra_lock_on_state_change = function (state) {
+                dispatch("stateChange", STATE_NAMES[state] || "unknown");
+            };
+            importObject.env.terra_lock_on_locks_fired = function (count) {
+                dispatch("locksFired", count);
+            };
+            importObject.env.terra_lock_on_game_over = function (ptr, len) {
+                dispatch("gameOver", JSON.parse(UTF8ToString(ptr, len)));
+            };
+        },
+    });
+
+    return {
+        onGameOver: function (callback) {
+            callbacks.gameOver.push(callback);
+        },
+        onLocksFired: function (callback) {
+            callbacks.locksFired.push(callback);
+        },
+        onStateChange: function (callback) {
+            callbacks.stateChange.push(callback);
+        },
+        // 設定変更（未知のキーや不正な値は警告を出して無視される）
+        setConfig: function (config) {
+            var wasm = exports();
+            if (wasm === null) {
+                return;
+            }
+            Object.keys(config).forEach(function (key) {
+                var id = CONFIG_KEYS.indexOf(key);
+                if (id < 0) {
+                    console.warn("Unknown Terra Lock config key: " + key);
+                } else if (!wasm.terra_lock_set_config(id, config[key])) {
+                    console.warn("Invalid Terra Lock config value: " + key + " = " + config[key]);
+                }
+            });
+        },
+        // シード指定でゲーム開始（省略時はランダム）
+        start: function (seed) {
+            var wasm = exports();
+            if (wasm === null) {
+                return;
+            }
+            if (seed === undefined) {
+                seed = Math.floor(Math.random() * 0xffffffff);
+            }
+            wasm.terra_lock_start(seed >>> 0);
+        },
+    };
+})();
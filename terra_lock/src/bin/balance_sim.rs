@@ -0,0 +1,203 @@
+// バランス調整用シミュレーター
+//
+// ボット（bot::Controller）を使ってヘッドレスで複数シードのゲームを実行し、
+// 生存時間・スコアの分布と、難易度段階別・敵機タイプ別の被弾原因を集計する。
+//
+// 使用例:
+//   cargo run --release --bin balance_sim -- --sessions 200 --seed 1 --policy greedy-lock
+
+use terra_lock::bot::{self, Controller};
+use terra_lock::game::{self, EnemyType, Game, GameState};
+use terra_lock::stats::RunStats;
+use terra_lock::timestep;
+
+// シミュレーションの固定ステップ（ゲーム本体と同じ120Hz）
+const STEP: f32 = timestep::FIXED_TIMESTEP;
+
+// 1セッションの結果
+struct SessionResult {
+    score: u32,
+    stats: RunStats,
+    timed_out: bool,
+}
+
+fn run_session(controller: &mut dyn Controller, seed: u64, max_time: f32) -> SessionResult {
+    let mut game = Game::new();
+    game.start_game_with_seed(seed);
+    controller.reset();
+
+    let mut elapsed = 0.0;
+    while game.state() == GameState::Playing && elapsed < max_time {
+        game.step_with_controller(STEP, controller);
+        game.drain_events(); // ホストページ通知用のイベントは使用しない
+        elapsed += STEP;
+    }
+
+    SessionResult {
+        score: game.score(),
+        stats: game.stats().clone(),
+        timed_out: game.state() == GameState::Playing,
+    }
+}
+
+// 分布の要約（最小・四分位・最大・平均）
+fn summarize(label: &str, mut values: Vec<f32>) {
+    if values.is_empty() {
+        return;
+    }
+    values.sort_by(|a, b| a.total_cmp(b));
+
+    let percentile = |p: f32| values[((values.len() - 1) as f32 * p).round() as usize];
+    let mean = values.iter().sum::<f32>() / values.len() as f32;
+    println!(
+        "{:<10} min {:>8.1}  p25 {:>8.1}  median {:>8.1}  p75 {:>8.1}  max {:>8.1}  mean {:>8.1}",
+        label,
+        values[0],
+        percentile(0.25),
+        percentile(0.5),
+        percentile(0.75),
+        values[values.len() - 1],
+        mean
+    );
+}
+
+fn report(policy_name: &str, options: &Options, results: &[SessionResult]) {
+    println!();
+    println!(
+        "=== {} ({} sessions, seeds {}..{}) ===",
+        policy_name,
+        results.len(),
+        options.seed,
+        options.seed + options.sessions as u64 - 1
+    );
+
+    summarize("Survival", results.iter().map(|r| r.stats.time_survived).collect());
+    summarize("Score", results.iter().map(|r| r.score as f32).collect());
+
+    let timeouts = results.iter().filter(|r| r.timed_out).count();
+    if timeouts > 0 {
+        println!("Survived until --max-time: {}", timeouts);
+    }
+
+    // 生存時間のヒストグラム（難易度段階ごと）
+    let step_time = game::GameConfig::new().difficulty_step_time;
+    let max_level = results.iter().map(|r| r.stats.difficulty_level).max().unwrap_or(0).max(0);
+    println!();
+    println!("Survival histogram:");
+    for level in 0..=max_level {
+        let count = results.iter().filter(|r| r.stats.difficulty_level == level).count();
+        println!(
+            "  {:>4.0}-{:<4.0}s | {:<40} {}",
+            level as f32 * step_time,
+            (level + 1) as f32 * step_time,
+            "#".repeat((count * 40).div_ceil(results.len())),
+            count
+        );
+    }
+
+    // 難易度段階別・敵機タイプ別の被弾原因
+    println!();
+    println!("Death causes by difficulty level:");
+    print!("  level interval max speed |");
+    for enemy_type in EnemyType::ALL {
+        print!(" {:>8}", enemy_type.name());
+    }
+    println!(" | {:>6}", "total");
+
+    for level in 0..=max_level {
+        let deaths: Vec<EnemyType> = results
+            .iter()
+            .filter(|r| r.stats.difficulty_level == level)
+            .filter_map(|r| r.stats.death_cause)
+            .collect();
+        if deaths.is_empty() {
+            continue;
+        }
+
+        let (spawn_interval, max_spawn, speed_multiplier) = Game::difficulty_parameters_for_level(level);
+        print!(
+            "  {:>5} {:>7.1}s {:>3} {:>4.1}x |",
+            level, spawn_interval, max_spawn, speed_multiplier
+        );
+        for enemy_type in EnemyType::ALL {
+            print!(" {:>8}", deaths.iter().filter(|&&cause| cause == enemy_type).count());
+        }
+        println!(" | {:>6}", deaths.len());
+    }
+}
+
+struct Options {
+    sessions: usize,
+    seed: u64,
+    policy: String,
+    max_time: f32,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        sessions: 100,
+        seed: 1,
+        policy: "all".to_string(),
+        max_time: 600.0,
+    };
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().ok_or(format!("missing value for {}", name));
+        match arg.as_str() {
+            "--sessions" => options.sessions = value("--sessions")?.parse().map_err(|e| format!("--sessions: {}", e))?,
+            "--seed" => options.seed = value("--seed")?.parse().map_err(|e| format!("--seed: {}", e))?,
+            "--policy" => options.policy = value("--policy")?,
+            "--max-time" => options.max_time = value("--max-time")?.parse().map_err(|e| format!("--max-time: {}", e))?,
+            "--help" | "-h" => return Err(String::new()),
+            other => return Err(format!("unknown argument: {}", other)),
+        }
+    }
+
+    if options.sessions == 0 {
+        return Err("--sessions must be at least 1".to_string());
+    }
+    Ok(options)
+}
+
+fn main() {
+    let options = match parse_options() {
+        Ok(options) => options,
+        Err(message) => {
+            if !message.is_empty() {
+                eprintln!("error: {}", message);
+            }
+            eprintln!(
+                "usage: balance_sim [--sessions N] [--seed S] [--policy {}|all] [--max-time SECONDS]",
+                bot::CONTROLLER_NAMES.join("|")
+            );
+            std::process::exit(2);
+        }
+    };
+
+    let names: Vec<&str> = if options.policy == "all" {
+        bot::CONTROLLER_NAMES.to_vec()
+    } else {
+        vec![options.policy.as_str()]
+    };
+    let mut policies = Vec::new();
+    for name in names {
+        match bot::controller_by_name(name) {
+            Some(controller) => policies.push(controller),
+            None => {
+                eprintln!("error: unknown policy: {}", name);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    // ゲーム内ログは大量に出るため無効化
+    game::set_logging(false);
+
+    for policy in &mut policies {
+        let results: Vec<SessionResult> = (0..options.sessions)
+            .map(|i| run_session(policy.as_mut(), options.seed + i as u64, options.max_time))
+            .collect();
+        report(policy.name(), &options, &results);
+    }
+}
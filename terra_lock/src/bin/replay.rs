@@ -0,0 +1,96 @@
+// リプレイの記録・期待値更新ツール
+//
+// 回帰テスト（tests/golden_replays.rs）のリプレイファイルを作成・更新する。
+// ゲームプレイの結果が変わる変更を意図して行った場合のみ update を実行すること。
+//
+// 使用例:
+//   cargo run --release --bin replay -- record --policy heuristic --seed 1 --max-time 60 tests/replays/heuristic_seed1.replay
+//   cargo run --release --bin replay -- update tests/replays/*.replay
+
+use terra_lock::bot;
+use terra_lock::game;
+use terra_lock::replay::Replay;
+use terra_lock::timestep::FIXED_TIMESTEP;
+
+fn usage() -> ! {
+    eprintln!("usage:");
+    eprintln!(
+        "  replay record [--policy {}] [--seed S] [--max-time SECONDS] FILE",
+        bot::CONTROLLER_NAMES.join("|")
+    );
+    eprintln!("  replay update FILE...");
+    std::process::exit(2);
+}
+
+fn fail(message: String) -> ! {
+    eprintln!("error: {}", message);
+    std::process::exit(1);
+}
+
+fn record(args: &[String]) {
+    let mut policy = "heuristic".to_string();
+    let mut seed = 1;
+    let mut max_time = 60.0;
+    let mut path = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let mut value = |name: &str| args.next().cloned().unwrap_or_else(|| fail(format!("missing value for {}", name)));
+        match arg.as_str() {
+            "--policy" => policy = value("--policy"),
+            "--seed" => seed = value("--seed").parse().unwrap_or_else(|e| fail(format!("--seed: {}", e))),
+            "--max-time" => max_time = value("--max-time").parse().unwrap_or_else(|e| fail(format!("--max-time: {}", e))),
+            other if !other.starts_with("--") && path.is_none() => path = Some(other.to_string()),
+            _ => usage(),
+        }
+    }
+    let Some(path) = path else { usage() };
+    let mut controller = bot::controller_by_name(&policy).unwrap_or_else(|| fail(format!("unknown policy: {}", policy)));
+
+    let max_steps = (max_time / FIXED_TIMESTEP).round() as usize;
+    let replay = Replay::record(controller.as_mut(), seed, max_steps);
+    std::fs::write(&path, replay.to_text()).unwrap_or_else(|e| fail(format!("{}: {}", path, e)));
+
+    let outcome = replay.expected.as_ref().expect("recorded replay has an outcome");
+    println!(
+        "{}: {} steps ({:.1}s), score {}, kills {}",
+        path,
+        outcome.steps,
+        outcome.steps as f32 * FIXED_TIMESTEP,
+        outcome.score,
+        outcome.kills_by_type.iter().sum::<u32>()
+    );
+}
+
+fn update(paths: &[String]) {
+    if paths.is_empty() {
+        usage();
+    }
+
+    for path in paths {
+        let text = std::fs::read_to_string(path).unwrap_or_else(|e| fail(format!("{}: {}", path, e)));
+        let mut replay = Replay::parse(&text).unwrap_or_else(|e| fail(format!("{}: {}", path, e)));
+
+        let actual = replay.play();
+        match replay.expected.as_ref().and_then(|expected| expected.describe_mismatch(&actual)) {
+            Some(difference) => println!("{}: updated\n  {}", path, difference.replace('\n', "\n  ")),
+            None if replay.expected.is_some() => println!("{}: unchanged", path),
+            None => println!("{}: expectations added", path),
+        }
+
+        replay.expected = Some(actual);
+        std::fs::write(path, replay.to_text()).unwrap_or_else(|e| fail(format!("{}: {}", path, e)));
+    }
+}
+
+fn main() {
+    // ゲーム内ログは大量に出るため無効化
+    game::set_logging(false);
+
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((command, rest)) if command == "record" => record(rest),
+        Some((command, rest)) if command == "update" => update(rest),
+        _ => usage(),
+    }
+}
@@ -0,0 +1,456 @@
+// ボット（自動操作）インターフェース
+//
+// Controller はゲームの観測情報から1フレーム分の入力を返す。
+// バランス調整シミュレーター、タイトル画面のデモ、ゲームプレイの回帰テストで共通に使用する。
+
+use macroquad::prelude::Vec2;
+
+use crate::game::{EnemyType, InputFrame, LockOnSystem};
+
+// 画面サイズ（ボットの位置取り用）
+const SCREEN_WIDTH: f32 = 800.0;
+const SCREEN_HEIGHT: f32 = 600.0;
+
+// ボットの移動速度上限（マウス操作相当、px/秒）
+const BOT_MOVE_SPEED: f32 = 400.0;
+
+// 観測用の敵機情報
+#[derive(Clone, Debug)]
+pub struct EnemyObservation {
+    pub position: Vec2,
+    pub velocity: Vec2,
+    pub enemy_type: EnemyType,
+    pub is_locked: bool,
+}
+
+// 観測用の敵弾情報
+#[derive(Clone, Debug)]
+pub struct EnemyBulletObservation {
+    pub position: Vec2,
+    pub velocity: Vec2,
+}
+
+// 観測用のホーミングレーザー情報
+#[derive(Clone, Debug)]
+pub struct HomingLaserObservation {
+    pub position: Vec2,        // 現在のレーザー先端位置
+    pub target_position: Vec2,
+    pub progress: f32,
+}
+
+// 観測用のロックオン状態
+#[derive(Clone, Debug, Default)]
+pub struct LockObservation {
+    pub active: bool,
+    pub center: Vec2,
+    pub radius: f32,
+    pub locked_count: usize,
+    pub max_targets: usize,
+    pub hold_time: f32,        // 左ボタンの長押し時間
+    pub long_press_time: f32,  // 長押し判定時間
+    pub energy: f32,           // ロックオンエネルギー
+    pub energy_per_lock: f32,  // レーザー1本あたりのエネルギー消費量
+}
+
+impl LockObservation {
+    // エネルギーの残量でロックオンをもう1つ追加できるか
+    pub fn can_afford_lock(&self) -> bool {
+        self.energy > 0.0 && self.energy >= self.energy_per_lock * (self.locked_count + 1) as f32
+    }
+}
+
+// ボットに渡すゲームの観測情報（Game::observe で生成）
+#[derive(Clone, Debug, Default)]
+pub struct Observation {
+    pub time: f32,             // ゲーム開始からの経過時間
+    pub player_position: Vec2,
+    pub enemies: Vec<EnemyObservation>,
+    pub enemy_bullets: Vec<EnemyBulletObservation>,
+    pub normal_lasers: Vec<Vec2>,
+    pub homing_lasers: Vec<HomingLaserObservation>,
+    pub lock: LockObservation,
+}
+
+impl Observation {
+    // 最も近い敵機（敵機、距離）
+    pub fn nearest_enemy(&self, from: Vec2) -> Option<(&EnemyObservation, f32)> {
+        self.enemies
+            .iter()
+            .map(|enemy| (enemy, enemy.position.distance(from)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+    }
+}
+
+// 観測情報から入力を決定するコントローラー
+pub trait Controller {
+    fn name(&self) -> &'static str;
+
+    // 新しいゲーム開始時の内部状態リセット
+    fn reset(&mut self) {}
+
+    fn next_input(&mut self, observation: &Observation, delta_time: f32) -> InputFrame;
+}
+
+// 目標位置へ速度制限付きで移動
+fn move_toward(current: Vec2, target: Vec2, max_distance: f32) -> Vec2 {
+    let offset = target - current;
+    if offset.length() <= max_distance {
+        target
+    } else {
+        current + offset.normalize() * max_distance
+    }
+}
+
+// 一定間隔で1フレームだけ押す（長押し判定にならない）通常レーザーの発射
+fn pulse_fire(fire_timer: &mut f32, interval: f32, delta_time: f32) -> bool {
+    *fire_timer -= delta_time;
+    let fire = *fire_timer <= 0.0;
+    if fire {
+        *fire_timer = interval;
+    }
+    fire
+}
+
+// 長押しを離すか（最大数ロック、時間切れ、エネルギー不足、危険時）
+// ロックオンがない場合も離し、ワイヤーフレームが閉じて再展開するまで押し続けない
+fn should_release(lock: &LockObservation, hold_time: f32, max_hold_time: f32, in_danger: bool) -> bool {
+    let full = lock.locked_count >= lock.max_targets;
+    let timed_out = hold_time >= max_hold_time;
+    let low_energy = lock.energy < LockOnSystem::MIN_OPEN_ENERGY
+        || (lock.locked_count > 0 && !lock.can_afford_lock());
+    full || timed_out || low_energy || in_danger
+}
+
+// 接近した敵機・敵弾から離れる方向の目標位置（危険がなければNone）
+fn dodge_target(observation: &Observation, danger_distance: f32) -> Option<Vec2> {
+    let player_pos = observation.player_position;
+    let (threat_position, distance) = observation
+        .enemies
+        .iter()
+        .map(|enemy| enemy.position)
+        .chain(observation.enemy_bullets.iter().map(|bullet| bullet.position))
+        .map(|position| (position, position.distance(player_pos)))
+        .min_by(|a, b| a.1.total_cmp(&b.1))?;
+    if distance > danger_distance {
+        return None;
+    }
+
+    let away = (player_pos - threat_position).normalize_or_zero();
+    let away = if away == Vec2::ZERO { Vec2::new(1.0, 0.0) } else { away };
+    Some(player_pos + away * danger_distance)
+}
+
+// 通常レーザーのみで戦うボット
+#[derive(Debug)]
+pub struct NormalFireBot {
+    fire_timer: f32,
+}
+
+impl NormalFireBot {
+    const FIRE_INTERVAL: f32 = 0.15;
+    const DANGER_DISTANCE: f32 = 60.0;
+
+    pub fn new() -> Self {
+        Self { fire_timer: 0.0 }
+    }
+}
+
+impl Default for NormalFireBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Controller for NormalFireBot {
+    fn name(&self) -> &'static str {
+        "normal-fire"
+    }
+
+    fn reset(&mut self) {
+        self.fire_timer = 0.0;
+    }
+
+    fn next_input(&mut self, observation: &Observation, delta_time: f32) -> InputFrame {
+        let player_pos = observation.player_position;
+
+        // 自機より上にいる最も近い敵機（通常レーザーを防ぐ敵機を除く）の真下に移動
+        let target = dodge_target(observation, Self::DANGER_DISTANCE).unwrap_or_else(|| {
+            observation.enemies
+                .iter()
+                .filter(|enemy| enemy.position.y < player_pos.y - 40.0 && !enemy.enemy_type.blocks_normal_lasers())
+                .min_by(|a, b| {
+                    (a.position.x - player_pos.x).abs().total_cmp(&(b.position.x - player_pos.x).abs())
+                })
+                .map(|enemy| Vec2::new(enemy.position.x, 520.0))
+                .unwrap_or(Vec2::new(400.0, 520.0))
+        });
+
+        let left_button_down = pulse_fire(&mut self.fire_timer, Self::FIRE_INTERVAL, delta_time);
+
+        InputFrame {
+            mouse_pos: move_toward(player_pos, target, BOT_MOVE_SPEED * delta_time),
+            left_button_down,
+            bomb_button_down: false,
+        }
+    }
+}
+
+// 長押しでロックオンを溜め、最大数または危険時に一斉発射するボット
+#[derive(Debug)]
+pub struct GreedyLockBot {
+    holding: bool,
+    hold_time: f32,
+    fire_timer: f32,
+}
+
+impl GreedyLockBot {
+    // 長押しの最大保持時間（ロックオンがなくても離す）
+    const MAX_HOLD_TIME: f32 = 3.0;
+    // エネルギー回復中の通常レーザーの発射間隔
+    const FIRE_INTERVAL: f32 = 0.15;
+    const DANGER_DISTANCE: f32 = 60.0;
+    // 長押しを始めるロックオンエネルギーの残量
+    const MIN_HOLD_ENERGY: f32 = 50.0;
+
+    pub fn new() -> Self {
+        Self { holding: false, hold_time: 0.0, fire_timer: 0.0 }
+    }
+}
+
+impl Default for GreedyLockBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Controller for GreedyLockBot {
+    fn name(&self) -> &'static str {
+        "greedy-lock"
+    }
+
+    fn reset(&mut self) {
+        self.holding = false;
+        self.hold_time = 0.0;
+        self.fire_timer = 0.0;
+    }
+
+    fn next_input(&mut self, observation: &Observation, delta_time: f32) -> InputFrame {
+        let player_pos = observation.player_position;
+        let dodge = dodge_target(observation, Self::DANGER_DISTANCE);
+
+        // 敵機群の重心の少し下に位置取り（ワイヤーフレームは自機中心）
+        let target = dodge.unwrap_or_else(|| {
+            let (sum, count) = observation.enemies
+                .iter()
+                .filter(|enemy| enemy.position.y > 0.0)
+                .fold((Vec2::ZERO, 0), |(sum, count), enemy| (sum + enemy.position, count + 1));
+            if count == 0 {
+                Vec2::new(400.0, 480.0)
+            } else {
+                let centroid = sum / count as f32;
+                Vec2::new(centroid.x, (centroid.y + 70.0).clamp(300.0, 560.0))
+            }
+        });
+
+        let left_button_down = if self.holding {
+            self.hold_time += delta_time;
+            if should_release(&observation.lock, self.hold_time, Self::MAX_HOLD_TIME, dodge.is_some()) {
+                // リリースで一斉発射（ロックオンがなければ何も発射しない）
+                self.holding = false;
+                self.hold_time = 0.0;
+            }
+            self.holding
+        } else if observation.lock.energy >= Self::MIN_HOLD_ENERGY && dodge.is_none() {
+            self.holding = true;
+            true
+        } else {
+            // エネルギー回復中・回避中は通常レーザーで攻撃
+            pulse_fire(&mut self.fire_timer, Self::FIRE_INTERVAL, delta_time)
+        };
+
+        InputFrame {
+            mouse_pos: move_toward(player_pos, target, BOT_MOVE_SPEED * delta_time),
+            left_button_down,
+            bomb_button_down: false,
+        }
+    }
+}
+
+// 追尾型を回避しつつ、6機同時ロックオンを狙うヒューリスティックボット
+#[derive(Debug)]
+pub struct HeuristicBot {
+    holding: bool,
+    hold_time: f32,
+    fire_timer: f32,
+}
+
+impl HeuristicBot {
+    // 敵機位置の予測時間（秒）
+    const LOOKAHEAD: f32 = 0.3;
+    // 危険距離（追尾型・自爆型・小型機は自機へ向かってくるため広めに取る）
+    const DANGER_DISTANCE: f32 = 60.0;
+    const HOMING_DANGER_DISTANCE: f32 = 90.0;
+    const BULLET_DANGER_DISTANCE: f32 = 40.0;
+    // ロックオン数が最大に届かない場合の最大保持時間（ロックオンがなくても離す）
+    const MAX_HOLD_TIME: f32 = 3.0;
+    // エネルギー回復中の通常レーザーの発射間隔
+    const FIRE_INTERVAL: f32 = 0.15;
+    // 長押しを始めるロックオンエネルギーの残量
+    const MIN_HOLD_ENERGY: f32 = 50.0;
+    // 敵機群に対する位置取り（斜め下、ワイヤーフレーム半径内）
+    const STANDOFF: Vec2 = Vec2::new(40.0, 90.0);
+    // 画面端に追い込まれないための余白
+    const EDGE_MARGIN: f32 = 80.0;
+
+    pub fn new() -> Self {
+        Self { holding: false, hold_time: 0.0, fire_timer: 0.0 }
+    }
+
+    fn danger_distance(enemy_type: EnemyType) -> f32 {
+        match enemy_type {
+            EnemyType::Homing | EnemyType::Kamikaze | EnemyType::Drone => Self::HOMING_DANGER_DISTANCE,
+            _ => Self::DANGER_DISTANCE,
+        }
+    }
+
+    // 接近する敵機・敵弾からの回避方向（追尾型・敵弾を重視、危険がなければゼロ）
+    fn threat_vector(observation: &Observation) -> Vec2 {
+        let player_pos = observation.player_position;
+        let mut threat = Vec2::ZERO;
+
+        for enemy in &observation.enemies {
+            let predicted = enemy.position + enemy.velocity * Self::LOOKAHEAD;
+            let danger = Self::danger_distance(enemy.enemy_type);
+            let offset = player_pos - predicted;
+            let distance = offset.length();
+
+            if distance < danger {
+                let weight = if enemy.enemy_type == EnemyType::Homing { 2.0 } else { 1.0 };
+                let direction = if distance > 0.0 { offset / distance } else { Vec2::new(1.0, 0.0) };
+                threat += direction * (1.0 - distance / danger) * weight;
+            }
+        }
+
+        for bullet in &observation.enemy_bullets {
+            let predicted = bullet.position + bullet.velocity * Self::LOOKAHEAD;
+            let offset = player_pos - predicted;
+            let distance = offset.length();
+            if distance < Self::BULLET_DANGER_DISTANCE {
+                let direction = if distance > 0.0 { offset / distance } else { Vec2::new(1.0, 0.0) };
+                threat += direction * (1.0 - distance / Self::BULLET_DANGER_DISTANCE) * 2.0;
+            }
+        }
+
+        threat
+    }
+
+    // ワイヤーフレームに最も多く入る敵機群の重心
+    fn densest_cluster(observation: &Observation) -> Option<Vec2> {
+        let radius = observation.lock.radius;
+        observation.enemies
+            .iter()
+            .filter(|enemy| enemy.position.y > 0.0)
+            .map(|center| {
+                let (sum, count) = observation.enemies
+                    .iter()
+                    .filter(|enemy| enemy.position.y > 0.0 && enemy.position.distance(center.position) < radius)
+                    .fold((Vec2::ZERO, 0), |(sum, count), enemy| (sum + enemy.position, count + 1));
+                (sum / count as f32, count)
+            })
+            .max_by_key(|&(_, count)| count)
+            .map(|(centroid, _)| centroid)
+    }
+
+    // 画面端から離れる方向への補正
+    fn edge_push(position: Vec2) -> Vec2 {
+        let mut push = Vec2::ZERO;
+        if position.x < Self::EDGE_MARGIN {
+            push.x += 1.0;
+        } else if position.x > SCREEN_WIDTH - Self::EDGE_MARGIN {
+            push.x -= 1.0;
+        }
+        if position.y < Self::EDGE_MARGIN {
+            push.y += 1.0;
+        } else if position.y > SCREEN_HEIGHT - Self::EDGE_MARGIN {
+            push.y -= 1.0;
+        }
+        push
+    }
+}
+
+impl Default for HeuristicBot {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Controller for HeuristicBot {
+    fn name(&self) -> &'static str {
+        "heuristic"
+    }
+
+    fn reset(&mut self) {
+        self.holding = false;
+        self.hold_time = 0.0;
+        self.fire_timer = 0.0;
+    }
+
+    fn next_input(&mut self, observation: &Observation, delta_time: f32) -> InputFrame {
+        let player_pos = observation.player_position;
+        let threat = Self::threat_vector(observation);
+        let in_danger = threat != Vec2::ZERO;
+
+        let target = if in_danger {
+            // 回避優先（画面端に追い込まれないよう補正）
+            let escape = (threat.normalize() + Self::edge_push(player_pos) * 0.5).normalize_or_zero();
+            player_pos + escape * Self::DANGER_DISTANCE
+        } else {
+            // 敵機群の斜め下からワイヤーフレームに収める（落下経路を避ける）
+            Self::densest_cluster(observation)
+                .map(|centroid| {
+                    let side = if centroid.x < SCREEN_WIDTH / 2.0 { 1.0 } else { -1.0 };
+                    Vec2::new(
+                        centroid.x + side * Self::STANDOFF.x,
+                        (centroid.y + Self::STANDOFF.y).clamp(250.0, 560.0),
+                    )
+                })
+                .unwrap_or(Vec2::new(400.0, 480.0))
+        };
+
+        let left_button_down = if self.holding {
+            self.hold_time += delta_time;
+            if should_release(&observation.lock, self.hold_time, Self::MAX_HOLD_TIME, in_danger) {
+                // 最大数ロック・エネルギー不足・時間切れまたは危険時に一斉発射
+                self.holding = false;
+                self.hold_time = 0.0;
+            }
+            self.holding
+        } else if observation.lock.energy >= Self::MIN_HOLD_ENERGY && !in_danger {
+            self.holding = true;
+            true
+        } else {
+            // エネルギー回復中・回避中は通常レーザーで攻撃
+            pulse_fire(&mut self.fire_timer, Self::FIRE_INTERVAL, delta_time)
+        };
+
+        InputFrame {
+            mouse_pos: move_toward(player_pos, target, BOT_MOVE_SPEED * delta_time),
+            left_button_down,
+            bomb_button_down: false,
+        }
+    }
+}
+
+
+// 名前からボットを生成（シミュレーター・デモ用）
+pub fn controller_by_name(name: &str) -> Option<Box<dyn Controller>> {
+    match name {
+        "normal-fire" => Some(Box::new(NormalFireBot::new())),
+        "greedy-lock" => Some(Box::new(GreedyLockBot::new())),
+        "heuristic" => Some(Box::new(HeuristicBot::new())),
+        _ => None,
+    }
+}
+
+// 組み込みボットの名前一覧
+pub const CONTROLLER_NAMES: [&str; 3] = ["heuristic", "greedy-lock", "normal-fire"];
@@ -0,0 +1,228 @@
+// 当たり判定（形状定義と判定関数）
+//
+// 各エンティティは Hitbox を持ち、形状の組み合わせに応じて判定する。
+// 高速に移動する物体は前回位置から今回位置への線分で掃引判定し、すり抜けを防ぐ。
+
+use macroquad::prelude::Vec2;
+
+// 当たり判定の形状（中心座標はエンティティの位置）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Hitbox {
+    Circle { radius: f32 },
+    Rect { half_size: Vec2 },  // 軸平行矩形（幅・高さの半分）
+}
+
+impl Hitbox {
+    // 形状を包む円の半径（空間分割の検索範囲用）
+    pub fn bounding_radius(self) -> f32 {
+        match self {
+            Hitbox::Circle { radius } => radius,
+            Hitbox::Rect { half_size } => half_size.length(),
+        }
+    }
+}
+
+// 2つの形状の重なり判定
+pub fn overlaps(a: Hitbox, a_position: Vec2, b: Hitbox, b_position: Vec2) -> bool {
+    match (a, b) {
+        (Hitbox::Circle { radius: a_radius }, Hitbox::Circle { radius: b_radius }) => {
+            a_position.distance_squared(b_position) <= (a_radius + b_radius).powi(2)
+        }
+        (Hitbox::Circle { radius }, Hitbox::Rect { half_size }) => {
+            circle_intersects_rect(a_position, radius, b_position, half_size)
+        }
+        (Hitbox::Rect { half_size }, Hitbox::Circle { radius }) => {
+            circle_intersects_rect(b_position, radius, a_position, half_size)
+        }
+        (Hitbox::Rect { half_size: a_half }, Hitbox::Rect { half_size: b_half }) => {
+            let distance = (a_position - b_position).abs();
+            distance.x <= a_half.x + b_half.x && distance.y <= a_half.y + b_half.y
+        }
+    }
+}
+
+// 移動する形状（start → end）と静止した形状の掃引判定
+// 移動側の矩形は外接円で近似する
+pub fn swept_overlaps(start: Vec2, end: Vec2, moving: Hitbox, target: Hitbox, target_position: Vec2) -> bool {
+    let moving_radius = moving.bounding_radius();
+    match target {
+        Hitbox::Circle { radius } => {
+            segment_intersects_circle(start, end, target_position, moving_radius + radius)
+        }
+        Hitbox::Rect { half_size } => {
+            segment_rect_distance_squared(start, end, target_position, half_size) <= moving_radius.powi(2)
+        }
+    }
+}
+
+// 円と矩形の当たり判定（円の中心から矩形への最短距離）
+pub fn circle_intersects_rect(center: Vec2, radius: f32, rect_center: Vec2, half_size: Vec2) -> bool {
+    let closest = center.clamp(rect_center - half_size, rect_center + half_size);
+    center.distance_squared(closest) <= radius.powi(2)
+}
+
+// 線分と円の当たり判定（線分上の最近点で判定）
+pub fn segment_intersects_circle(start: Vec2, end: Vec2, center: Vec2, radius: f32) -> bool {
+    closest_point_on_segment(start, end, center).distance_squared(center) <= radius.powi(2)
+}
+
+pub fn closest_point_on_segment(start: Vec2, end: Vec2, point: Vec2) -> Vec2 {
+    let segment = end - start;
+    let length_squared = segment.length_squared();
+    if length_squared == 0.0 {
+        return start;
+    }
+    let t = ((point - start).dot(segment) / length_squared).clamp(0.0, 1.0);
+    start + segment * t
+}
+
+// 線分と矩形の最短距離の2乗（交差時は0）
+fn segment_rect_distance_squared(start: Vec2, end: Vec2, rect_center: Vec2, half_size: Vec2) -> f32 {
+    let min = rect_center - half_size;
+    let max = rect_center + half_size;
+    if segment_intersects_rect(start, end, min, max) {
+        return 0.0;
+    }
+
+    // 交差しない場合、最短距離は線分の端点か矩形の頂点で決まる
+    let point_distance = |point: Vec2| point.distance_squared(point.clamp(min, max));
+    let corners = [min, Vec2::new(max.x, min.y), max, Vec2::new(min.x, max.y)];
+    corners
+        .iter()
+        .map(|&corner| closest_point_on_segment(start, end, corner).distance_squared(corner))
+        .fold(point_distance(start).min(point_distance(end)), f32::min)
+}
+
+// 線分と矩形の交差判定（スラブ法）
+fn segment_intersects_rect(start: Vec2, end: Vec2, min: Vec2, max: Vec2) -> bool {
+    let direction = end - start;
+    let mut t_min: f32 = 0.0;
+    let mut t_max: f32 = 1.0;
+
+    for axis in 0..2 {
+        if direction[axis] == 0.0 {
+            if start[axis] < min[axis] || start[axis] > max[axis] {
+                return false;
+            }
+        } else {
+            let t1 = (min[axis] - start[axis]) / direction[axis];
+            let t2 = (max[axis] - start[axis]) / direction[axis];
+            t_min = t_min.max(t1.min(t2));
+            t_max = t_max.min(t1.max(t2));
+            if t_min > t_max {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+// 点と三角形の内外判定（頂点の並び順は問わない、辺上は内側）
+pub fn point_in_triangle(point: Vec2, triangle: [Vec2; 3]) -> bool {
+    let [a, b, c] = triangle;
+    let d1 = (b - a).perp_dot(point - a);
+    let d2 = (c - b).perp_dot(point - b);
+    let d3 = (a - c).perp_dot(point - c);
+    let has_negative = d1 < 0.0 || d2 < 0.0 || d3 < 0.0;
+    let has_positive = d1 > 0.0 || d2 > 0.0 || d3 > 0.0;
+    !(has_negative && has_positive)
+}
+
+// 線分と三角形の交差判定（端点が内側にあるか、いずれかの辺と交差）
+pub fn segment_intersects_triangle(start: Vec2, end: Vec2, triangle: [Vec2; 3]) -> bool {
+    if point_in_triangle(start, triangle) || point_in_triangle(end, triangle) {
+        return true;
+    }
+    (0..3).any(|i| segments_intersect(start, end, triangle[i], triangle[(i + 1) % 3]))
+}
+
+// 2線分の交差判定（端点の接触を含む、同一直線上の重なりは端点の内外判定で扱う）
+fn segments_intersect(a1: Vec2, a2: Vec2, b1: Vec2, b2: Vec2) -> bool {
+    let d1 = (a2 - a1).perp_dot(b1 - a1);
+    let d2 = (a2 - a1).perp_dot(b2 - a1);
+    let d3 = (b2 - b1).perp_dot(a1 - b1);
+    let d4 = (b2 - b1).perp_dot(a2 - b1);
+    d1 * d2 <= 0.0 && d3 * d4 <= 0.0 && !(d1 == 0.0 && d2 == 0.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLAYER: Hitbox = Hitbox::Rect { half_size: Vec2::new(10.0, 7.5) };
+    const ENEMY: Hitbox = Hitbox::Circle { radius: 10.0 };
+    const POINT: Hitbox = Hitbox::Circle { radius: 0.0 };
+
+    #[test]
+    fn circle_intersects_rect_on_edges_and_corners() {
+        let rect_center = Vec2::new(400.0, 500.0);
+        let half_size = Vec2::new(10.0, 7.5);
+
+        // 内部・辺に接する位置
+        assert!(circle_intersects_rect(rect_center, 10.0, rect_center, half_size));
+        assert!(circle_intersects_rect(Vec2::new(420.0, 500.0), 10.0, rect_center, half_size));
+        assert!(circle_intersects_rect(Vec2::new(400.0, 517.5), 10.0, rect_center, half_size));
+        assert!(!circle_intersects_rect(Vec2::new(420.5, 500.0), 10.0, rect_center, half_size));
+
+        // 角の近く：外接矩形内でも角からの距離が半径を超える場合は当たらない
+        assert!(!circle_intersects_rect(Vec2::new(418.0, 515.0), 10.0, rect_center, half_size));
+        assert!(circle_intersects_rect(Vec2::new(416.0, 513.0), 10.0, rect_center, half_size));
+    }
+
+    #[test]
+    fn overlaps_is_symmetric_for_rect_and_circle() {
+        let enemy_position = Vec2::new(17.5, 14.5);
+        assert_eq!(
+            overlaps(PLAYER, Vec2::ZERO, ENEMY, enemy_position),
+            overlaps(ENEMY, enemy_position, PLAYER, Vec2::ZERO)
+        );
+        assert!(overlaps(PLAYER, Vec2::ZERO, ENEMY, Vec2::new(19.0, 0.0)));
+        assert!(!overlaps(PLAYER, Vec2::ZERO, ENEMY, enemy_position));
+        assert!(overlaps(PLAYER, Vec2::ZERO, PLAYER, Vec2::new(20.0, 15.0)));
+        assert!(!overlaps(PLAYER, Vec2::ZERO, PLAYER, Vec2::new(20.5, 0.0)));
+    }
+
+    #[test]
+    fn swept_overlaps_detects_tunnelling_through_a_circle() {
+        // 1ステップで敵機を飛び越える移動でも当たる
+        let start = Vec2::new(0.0, 30.0);
+        let end = Vec2::new(0.0, -30.0);
+        assert!(!overlaps(POINT, start, ENEMY, Vec2::ZERO));
+        assert!(!overlaps(POINT, end, ENEMY, Vec2::ZERO));
+        assert!(swept_overlaps(start, end, POINT, ENEMY, Vec2::ZERO));
+
+        assert!(!swept_overlaps(Vec2::new(11.0, 30.0), Vec2::new(11.0, -30.0), POINT, ENEMY, Vec2::ZERO));
+    }
+
+    #[test]
+    fn swept_overlaps_against_rect_uses_moving_radius() {
+        let laser = Hitbox::Circle { radius: 1.0 };
+        assert!(swept_overlaps(Vec2::new(-50.0, 0.0), Vec2::new(50.0, 0.0), POINT, PLAYER, Vec2::ZERO));
+        assert!(swept_overlaps(Vec2::new(-50.0, 8.4), Vec2::new(50.0, 8.4), laser, PLAYER, Vec2::ZERO));
+        assert!(!swept_overlaps(Vec2::new(-50.0, 9.0), Vec2::new(50.0, 9.0), laser, PLAYER, Vec2::ZERO));
+
+        // 角に最も近づく斜めの移動（角(10, 7.5)から(20, 20)までの距離は16）
+        let moving = Hitbox::Circle { radius: 15.0 };
+        assert!(!swept_overlaps(Vec2::new(20.0, 20.0), Vec2::new(30.0, 30.0), moving, PLAYER, Vec2::ZERO));
+    }
+
+    #[test]
+    fn closest_point_on_degenerate_segment_is_its_start() {
+        let point = closest_point_on_segment(Vec2::new(3.0, 4.0), Vec2::new(3.0, 4.0), Vec2::ZERO);
+        assert_eq!(point, Vec2::new(3.0, 4.0));
+    }
+
+    #[test]
+    fn segment_intersects_triangle_when_crossing_or_inside() {
+        let triangle = [Vec2::new(0.0, 0.0), Vec2::new(-10.0, -20.0), Vec2::new(10.0, -20.0)];
+        assert!(point_in_triangle(Vec2::new(0.0, -10.0), triangle));
+        assert!(!point_in_triangle(Vec2::new(8.0, -5.0), triangle));
+
+        // 三角形を横切る線分（端点はどちらも外側）
+        assert!(segment_intersects_triangle(Vec2::new(-30.0, -15.0), Vec2::new(30.0, -15.0), triangle));
+        // 内側に収まる線分
+        assert!(segment_intersects_triangle(Vec2::new(-1.0, -15.0), Vec2::new(1.0, -15.0), triangle));
+        // 三角形の外を平行に通る線分
+        assert!(!segment_intersects_triangle(Vec2::new(-30.0, 5.0), Vec2::new(30.0, 5.0), triangle));
+    }
+}
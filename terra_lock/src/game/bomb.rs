@@ -0,0 +1,77 @@
+// ボム（残数制の緊急回避、画面内の敵機へのダメージ・敵弾の消去と無敵時間）
+
+use macroquad::prelude::*;
+
+use super::scoring::BOMB_SCORE;
+use super::Game;
+
+// 画面内の敵機に与えるダメージ（耐久力の高い敵機は生き残る）
+pub const BOMB_DAMAGE: u8 = 2;
+
+// 発動後の自機の無敵時間（秒）
+pub const BOMB_INVULNERABLE_TIME: f32 = 1.5;
+
+// ボムの範囲（画面内、出現前の画面外の敵機は対象外）
+const BOMB_AREA_MIN: Vec2 = Vec2::new(0.0, 0.0);
+const BOMB_AREA_MAX: Vec2 = Vec2::new(800.0, 600.0);
+
+// 画面全体のエフェクト（発動位置から広がる衝撃波と閃光）
+#[derive(Clone, Debug)]
+pub struct BombBlast {
+    pub center: Vec2,
+    pub timer: f32,
+}
+
+impl BombBlast {
+    pub const DURATION: f32 = 0.6;
+
+    // エフェクトの進行度（0.0〜1.0）
+    pub fn progress(&self) -> f32 {
+        (self.timer / Self::DURATION).min(1.0)
+    }
+}
+
+impl Game {
+    // ボムの発動（押した瞬間のみ、残数がある場合）と無敵時間・エフェクトの更新
+    pub(super) fn update_bomb(&mut self, delta_time: f32) {
+        self.player.invulnerable_time = (self.player.invulnerable_time - delta_time).max(0.0);
+        if let Some(blast) = &mut self.bomb_blast {
+            blast.timer += delta_time;
+            if blast.timer >= BombBlast::DURATION {
+                self.bomb_blast = None;
+            }
+        }
+
+        if self.input.bomb_just_pressed && self.bombs > 0 {
+            self.detonate_bomb();
+        }
+    }
+
+    fn detonate_bomb(&mut self) {
+        self.bombs -= 1;
+        self.stats.bombs_used += 1;
+        self.player.invulnerable_time = BOMB_INVULNERABLE_TIME;
+        self.bomb_blast = Some(BombBlast { center: self.player.position, timer: 0.0 });
+
+        // 敵弾をすべて消去
+        self.enemy_bullets.clear();
+
+        // 画面内の敵機にダメージを与え、撃破した敵機を削除（ボムの撃破点は通常より低い）
+        self.enemies_to_remove.clear();
+        for (enemy_idx, enemy) in self.enemies.iter_mut().enumerate() {
+            let on_screen = enemy.position.cmpge(BOMB_AREA_MIN).all() && enemy.position.cmple(BOMB_AREA_MAX).all();
+            if on_screen {
+                enemy.hp = enemy.hp.saturating_sub(BOMB_DAMAGE);
+                if enemy.hp == 0 {
+                    self.enemies_to_remove.push(enemy_idx);
+                }
+            }
+        }
+
+        let kill_count = self.enemies_to_remove.len();
+        self.score += kill_count as u32 * BOMB_SCORE;
+        self.remove_enemies(true);
+
+        game_log!("Bomb! {} enemies destroyed, {} bombs left", kill_count, self.bombs);
+    }
+}
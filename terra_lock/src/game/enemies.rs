@@ -0,0 +1,482 @@
+// 敵機（タイプ別の形状・動作パターン、削除と自機との当たり判定）
+
+use macroquad::prelude::*;
+
+use super::{Game, GameEvent, GameState};
+use crate::collision::{self, Hitbox};
+
+// 敵機タイプ
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnemyType {
+    Straight,    // 直線移動
+    Zigzag,      // ジグザグ移動
+    Arc,         // 円弧移動
+    Homing,      // 追尾移動
+    Shooter,     // 停止して自機を狙って射撃
+    Splitter,    // 撃破されると小型機に分裂
+    Shielded,    // シールドで通常レーザーを防ぐ（ロックオンのみ有効）
+    Kamikaze,    // ロックオンされると自機へ突撃
+    Carrier,     // 停止して小型機を発進させる大型機
+    Drone,       // 小型機（分裂・母艦から発進、発進時の速度で直進）
+}
+
+impl EnemyType {
+    pub const COUNT: usize = 10;
+    pub const ALL: [EnemyType; EnemyType::COUNT] = [
+        EnemyType::Straight,
+        EnemyType::Zigzag,
+        EnemyType::Arc,
+        EnemyType::Homing,
+        EnemyType::Shooter,
+        EnemyType::Splitter,
+        EnemyType::Shielded,
+        EnemyType::Kamikaze,
+        EnemyType::Carrier,
+        EnemyType::Drone,
+    ];
+
+    // 統計配列のインデックス
+    pub fn index(self) -> usize {
+        match self {
+            EnemyType::Straight => 0,
+            EnemyType::Zigzag => 1,
+            EnemyType::Arc => 2,
+            EnemyType::Homing => 3,
+            EnemyType::Shooter => 4,
+            EnemyType::Splitter => 5,
+            EnemyType::Shielded => 6,
+            EnemyType::Kamikaze => 7,
+            EnemyType::Carrier => 8,
+            EnemyType::Drone => 9,
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            EnemyType::Straight => "straight",
+            EnemyType::Zigzag => "zigzag",
+            EnemyType::Arc => "arc",
+            EnemyType::Homing => "homing",
+            EnemyType::Shooter => "shooter",
+            EnemyType::Splitter => "splitter",
+            EnemyType::Shielded => "shielded",
+            EnemyType::Kamikaze => "kamikaze",
+            EnemyType::Carrier => "carrier",
+            EnemyType::Drone => "drone",
+        }
+    }
+    
+    // タイプ別の当たり判定形状（描画の形状と同じ大きさ、基本の4タイプは直径20pxの円）
+    pub fn hitbox(self) -> Hitbox {
+        match self {
+            EnemyType::Straight | EnemyType::Zigzag | EnemyType::Arc | EnemyType::Homing => {
+                Hitbox::Circle { radius: 10.0 }
+            }
+            EnemyType::Shooter => Hitbox::Rect { half_size: Vec2::new(10.0, 10.0) },
+            EnemyType::Splitter => Hitbox::Circle { radius: 12.0 },
+            EnemyType::Shielded => Hitbox::Circle { radius: 14.0 }, // シールドの外周
+            EnemyType::Kamikaze => Hitbox::Circle { radius: 9.0 },
+            EnemyType::Carrier => Hitbox::Rect { half_size: Vec2::new(24.0, 12.0) },
+            EnemyType::Drone => Hitbox::Circle { radius: 6.0 },
+        }
+    }
+    
+    // タイプ別の耐久力（命中1回で1減少し、0で撃破）
+    // 2以上の敵機には耐久力の数までロックオンを重ねられる
+    pub fn hit_points(self) -> u8 {
+        match self {
+            EnemyType::Shielded => 2,
+            EnemyType::Carrier => 3,
+            _ => 1,
+        }
+    }
+    
+    // 通常レーザーを防ぐか（レーザーは消滅し、ダメージなし）
+    pub fn blocks_normal_lasers(self) -> bool {
+        self == EnemyType::Shielded
+    }
+    
+    // 全タイプ中で最大の外接円半径（空間分割の検索範囲用）
+    pub fn max_hitbox_radius() -> f32 {
+        Self::ALL.iter().map(|enemy_type| enemy_type.hitbox().bounding_radius()).fold(0.0, f32::max)
+    }
+}
+
+// 射撃型：停止位置・停止時間・射撃間隔
+const SHOOTER_STOP_Y: f32 = 140.0;
+const SHOOTER_HOVER_TIME: f32 = 5.0;
+const SHOOTER_FIRE_INTERVAL: f32 = 1.2;
+
+// 母艦：停止位置・停止時間・小型機の発進間隔
+const CARRIER_STOP_Y: f32 = 90.0;
+const CARRIER_HOVER_TIME: f32 = 8.0;
+const CARRIER_LAUNCH_INTERVAL: f32 = 2.0;
+
+// 停止型の最初の行動までの時間（停止してから）
+const FIRST_ACTION_DELAY: f32 = 0.5;
+
+// 小型機の速度（基本速度に対する倍率）と、分裂時の数・広がり
+const DRONE_SPEED_MULTIPLIER: f32 = 1.3;
+const SPLIT_COUNT: usize = 3;
+const SPLIT_SPREAD: f32 = 0.7; // ラジアン（下向きから左右へ）
+
+// 自爆型の突撃速度（基本速度に対する倍率）
+const KAMIKAZE_CHARGE_MULTIPLIER: f32 = 3.0;
+
+// 敵弾：速度・当たり判定
+const ENEMY_BULLET_SPEED: f32 = 220.0;
+const ENEMY_BULLET_HITBOX: Hitbox = Hitbox::Circle { radius: 4.0 };
+
+// 画面外の敵機を削除する範囲（下端は出現済みの敵機の退場、左右・上端は突撃・発進した敵機用）
+const OFFSCREEN_BOTTOM: f32 = 650.0;
+const OFFSCREEN_TOP: f32 = -150.0;
+const OFFSCREEN_LEFT: f32 = -100.0;
+const OFFSCREEN_RIGHT: f32 = 900.0;
+
+// 敵機構造体
+#[derive(Clone, Debug)]
+pub struct Enemy {
+    pub position: Vec2,
+    pub previous_position: Vec2, // 前回ステップの位置（描画補間用・掃引判定用）
+    pub hitbox: Hitbox,
+    pub velocity: Vec2,
+    pub is_locked: bool,
+    pub lock_stack: u8,          // 重ねたロックオン数（発射されるホーミングレーザー数）
+    pub lock_timer: f32,         // ワイヤーフレーム内の滞在時間（ロックオン進捗、範囲外で0に戻る）
+    pub hp: u8,                  // 残り耐久力
+    pub enemy_type: EnemyType,
+    pub spawn_time: f32,     // 出現時刻（動作パターン計算用）
+    pub base_speed: f32,     // 基本速度
+    pub hover_time: f32,     // 停止してからの時間（射撃型・母艦）
+    pub action_timer: f32,   // 次の行動（射撃・発進）までの時間
+    pub charging: bool,      // 突撃中（自爆型）
+}
+
+// 敵弾（射撃型が自機を狙って発射、直進）
+#[derive(Clone, Debug)]
+pub struct EnemyBullet {
+    pub position: Vec2,
+    pub previous_position: Vec2, // 前回ステップの位置（描画補間用）
+    pub velocity: Vec2,
+    pub hitbox: Hitbox,
+}
+
+impl Game {
+    // 敵機の更新（タイプ別動作パターン、射撃・発進を含む）
+    pub(super) fn update_enemies(&mut self, delta_time: f32) {
+        let current_time = self.time;
+        let player_pos = self.player.position; // プレイヤー位置を事前に取得
+        
+        for enemy in &mut self.enemies {
+            let elapsed_time = current_time - enemy.spawn_time;
+            
+            match enemy.enemy_type {
+                EnemyType::Straight => {
+                    // 直線移動（既存の動作）
+                    enemy.velocity = Vec2::new(0.0, enemy.base_speed);
+                }
+                
+                EnemyType::Zigzag => {
+                    // ジグザグ移動
+                    let zigzag_frequency = 2.0; // 振動の周波数
+                    let zigzag_amplitude = 80.0; // 振幅
+                    let horizontal_speed = (elapsed_time * zigzag_frequency).sin() * zigzag_amplitude;
+                    enemy.velocity = Vec2::new(horizontal_speed, enemy.base_speed);
+                }
+                
+                EnemyType::Arc => {
+                    // 円弧移動
+                    let arc_frequency = 1.5;
+                    let arc_amplitude = 60.0;
+                    let horizontal_speed = (elapsed_time * arc_frequency).cos() * arc_amplitude;
+                    let vertical_speed = enemy.base_speed * 0.8; // 少し遅めに下降
+                    enemy.velocity = Vec2::new(horizontal_speed, vertical_speed);
+                }
+                
+                EnemyType::Homing => {
+                    // 追尾移動（プレイヤーに向かう）
+                    let direction = (player_pos - enemy.position).normalize();
+                    let homing_speed = enemy.base_speed * 0.7; // 追尾は少し遅め
+                    enemy.velocity = direction * homing_speed;
+                }
+                
+                EnemyType::Shooter => {
+                    // 停止位置まで下降し、停止中は自機を狙って射撃、停止時間を過ぎると下へ退場
+                    if hover(enemy, SHOOTER_STOP_Y, SHOOTER_HOVER_TIME, SHOOTER_FIRE_INTERVAL, delta_time) {
+                        let direction = (player_pos - enemy.position).normalize_or(Vec2::Y);
+                        self.enemy_bullets.spawn(EnemyBullet {
+                            position: enemy.position,
+                            previous_position: enemy.position,
+                            velocity: direction * ENEMY_BULLET_SPEED,
+                            hitbox: ENEMY_BULLET_HITBOX,
+                        });
+                    }
+                }
+                
+                EnemyType::Splitter => {
+                    // ゆっくり直進（撃破時の分裂は remove_enemies で処理）
+                    enemy.velocity = Vec2::new(0.0, enemy.base_speed * 0.8);
+                }
+                
+                EnemyType::Shielded => {
+                    // シールドの分だけ重く、遅めに直進
+                    enemy.velocity = Vec2::new(0.0, enemy.base_speed * 0.6);
+                }
+                
+                EnemyType::Kamikaze => {
+                    // ロックオンされた時点の自機の方向へ突撃（ロックオンが外れても止まらない）
+                    if enemy.is_locked && !enemy.charging {
+                        enemy.charging = true;
+                        let direction = (player_pos - enemy.position).normalize_or(Vec2::Y);
+                        enemy.velocity = direction * enemy.base_speed * KAMIKAZE_CHARGE_MULTIPLIER;
+                    } else if !enemy.charging {
+                        enemy.velocity = Vec2::new(0.0, enemy.base_speed * 0.5);
+                    }
+                }
+                
+                EnemyType::Carrier => {
+                    // 停止位置まで下降し、停止中は自機へ向けて小型機を発進させる
+                    if hover(enemy, CARRIER_STOP_Y, CARRIER_HOVER_TIME, CARRIER_LAUNCH_INTERVAL, delta_time) {
+                        let direction = (player_pos - enemy.position).normalize_or(Vec2::Y);
+                        let launch_position = enemy.position + Vec2::new(0.0, 16.0);
+                        let velocity = direction * enemy.base_speed * DRONE_SPEED_MULTIPLIER;
+                        self.enemy_spawn_queue.push((EnemyType::Drone, launch_position, velocity));
+                    }
+                }
+                
+                EnemyType::Drone => {
+                    // 発進時の速度のまま直進
+                }
+            }
+            
+            // 位置を更新
+            enemy.position += enemy.velocity * delta_time;
+        }
+        
+        // 発進した小型機を追加
+        self.spawn_queued_enemies();
+    }
+    
+    // 分裂・発進で生成待ちの敵機を追加（敵機の反復・削除中は追加できないため後でまとめて追加）
+    pub(super) fn spawn_queued_enemies(&mut self) {
+        let queue = std::mem::take(&mut self.enemy_spawn_queue);
+        for &(enemy_type, position, velocity) in &queue {
+            let base_speed = velocity.length();
+            if let Some(enemy_idx) = self.spawn_enemy(enemy_type, position, base_speed) {
+                self.enemies[enemy_idx].velocity = velocity;
+            }
+        }
+        self.enemy_spawn_queue = queue;
+        self.enemy_spawn_queue.clear();
+    }
+    
+    // 敵弾の移動と画面外の敵弾の削除
+    pub(super) fn update_enemy_bullets(&mut self, delta_time: f32) {
+        for bullet in &mut self.enemy_bullets {
+            bullet.previous_position = bullet.position;
+            bullet.position += bullet.velocity * delta_time;
+        }
+        self.enemy_bullets.retain(|bullet| {
+            (OFFSCREEN_LEFT..=OFFSCREEN_RIGHT).contains(&bullet.position.x)
+                && (OFFSCREEN_TOP..=OFFSCREEN_BOTTOM).contains(&bullet.position.y)
+        });
+    }
+    
+    // enemies_to_remove の敵機を削除（ロックオン・ホーミングレーザーの参照も調整）
+    pub(super) fn remove_enemies(&mut self, destroyed: bool) {
+        if self.enemies_to_remove.is_empty() {
+            return;
+        }
+        
+        // 重複を除去してソート
+        self.enemies_to_remove.sort_unstable();
+        self.enemies_to_remove.dedup();
+        
+        // ロックオン解除とターゲットID調整（敵機削除前に実行）
+        let removed = std::mem::take(&mut self.enemies_to_remove);
+        self.lock_system.remove_destroyed_enemies(&removed, self.enemies.len());
+        self.update_homing_laser_targets(&removed);
+        
+        // 逆順で削除（末尾との入れ替え）
+        for &idx in removed.iter().rev() {
+            if destroyed {
+                let enemy = &self.enemies[idx];
+                self.stats.record_kill(enemy.enemy_type);
+                self.lock_system.restore_energy(self.lock_system.energy_per_kill);
+                
+                // 分裂型は下向きに扇状に広がる小型機に分裂
+                if enemy.enemy_type == EnemyType::Splitter {
+                    let speed = enemy.base_speed * DRONE_SPEED_MULTIPLIER;
+                    for i in 0..SPLIT_COUNT {
+                        let angle = SPLIT_SPREAD * (i as f32 / (SPLIT_COUNT - 1) as f32 * 2.0 - 1.0);
+                        let velocity = Vec2::Y.rotate(Vec2::from_angle(angle)) * speed;
+                        self.enemy_spawn_queue.push((EnemyType::Drone, enemy.position, velocity));
+                    }
+                }
+            }
+            self.enemies.swap_remove(idx);
+        }
+        
+        self.enemies_to_remove = removed;
+        self.enemies_to_remove.clear();
+        
+        // 分裂した小型機は削除後に追加（ロックオン・ホーミングレーザーの添字に影響しない）
+        self.spawn_queued_enemies();
+    }
+    
+    // 画面外（下端、突撃・発進した敵機は左右・上端も）に出た敵機を削除
+    pub(super) fn remove_offscreen_enemies(&mut self) {
+        self.enemies_to_remove.clear();
+        self.enemies_to_remove.extend((0..self.enemies.len()).filter(|&idx| {
+            let position = self.enemies[idx].position;
+            position.y >= OFFSCREEN_BOTTOM
+                || position.y < OFFSCREEN_TOP
+                || position.x < OFFSCREEN_LEFT
+                || position.x > OFFSCREEN_RIGHT
+        }));
+        self.remove_enemies(false);
+    }
+    
+    // 敵機グリッドの再構築（敵機の移動・削除後に呼び出す）
+    pub(super) fn rebuild_enemy_grid(&mut self) {
+        self.enemy_grid.clear();
+        for (enemy_idx, enemy) in self.enemies.iter().enumerate() {
+            self.enemy_grid.insert(enemy_idx, enemy.position);
+        }
+    }
+    
+    pub(super) fn check_player_enemy_collision(&mut self) {
+        // ボム発動後の無敵時間中は判定しない
+        if self.player.invulnerable_time > 0.0 {
+            return;
+        }
+        
+        // 自機周辺のセルの敵機のみ判定
+        self.rebuild_enemy_grid();
+        self.query_buffer.clear();
+        let query_radius = self.player.hitbox.bounding_radius() + EnemyType::max_hitbox_radius();
+        self.enemy_grid.query_circle(self.player.position, query_radius, &mut self.query_buffer);
+        self.query_buffer.sort_unstable();
+        
+        // 自機と敵機の形状による当たり判定（既定は矩形と円）
+        let player = &self.player;
+        let hit_enemy = self.query_buffer.iter().map(|&enemy_idx| &self.enemies[enemy_idx]).find(|enemy| {
+            collision::overlaps(player.hitbox, player.position, enemy.hitbox, enemy.position)
+        });
+        
+        if let Some(enemy_type) = hit_enemy.map(|enemy| enemy.enemy_type) {
+            self.player_hit(enemy_type);
+            game_log!("Player hit by enemy! Game Over!");
+            return;
+        }
+        
+        // 自機周辺のセルの敵弾との当たり判定（被弾原因は射撃型）
+        self.bullet_grid.clear();
+        for (bullet_idx, bullet) in self.enemy_bullets.iter().enumerate() {
+            self.bullet_grid.insert(bullet_idx, bullet.position);
+        }
+        self.query_buffer.clear();
+        let query_radius = self.player.hitbox.bounding_radius() + ENEMY_BULLET_HITBOX.bounding_radius();
+        self.bullet_grid.query_circle(self.player.position, query_radius, &mut self.query_buffer);
+        
+        let player = &self.player;
+        let hit_by_bullet = self.query_buffer.iter().map(|&bullet_idx| &self.enemy_bullets[bullet_idx]).any(|bullet| {
+            collision::overlaps(player.hitbox, player.position, bullet.hitbox, bullet.position)
+        });
+        if hit_by_bullet {
+            self.player_hit(EnemyType::Shooter);
+            game_log!("Player hit by enemy bullet! Game Over!");
+        }
+    }
+    
+    fn player_hit(&mut self, cause: EnemyType) {
+        if self.state == GameState::Demo {
+            // デモプレイ中の被弾はタイトル画面へ戻る
+            self.end_demo();
+            return;
+        }
+        
+        // 自機が被弾した場合、ゲーム状態をGameOverに変更
+        self.stats.death_cause = Some(cause);
+        self.stats.score = self.score;
+        self.stats.time_survived = self.time - self.game_start_time;
+        self.high_scores[self.ship].record(self.score);
+        self.set_state(GameState::GameOver);
+        self.emit(GameEvent::GameOver(self.stats.clone()));
+    }
+}
+
+// 停止型の動作（停止位置まで下降、停止時間中は静止して一定間隔で行動、その後は下へ退場）
+// 行動するステップでtrue
+fn hover(enemy: &mut Enemy, stop_y: f32, hover_duration: f32, action_interval: f32, delta_time: f32) -> bool {
+    if enemy.position.y < stop_y || enemy.hover_time >= hover_duration {
+        enemy.velocity = Vec2::new(0.0, enemy.base_speed);
+        return false;
+    }
+    
+    enemy.velocity = Vec2::ZERO;
+    if enemy.hover_time == 0.0 {
+        enemy.action_timer = FIRST_ACTION_DELAY;
+    }
+    enemy.hover_time += delta_time;
+    enemy.action_timer -= delta_time;
+    if enemy.action_timer <= 0.0 {
+        enemy.action_timer += action_interval;
+        true
+    } else {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_support::{game_with_enemies, homing_laser};
+    
+    #[test]
+    fn remove_enemies_keeps_lock_and_laser_targets_on_the_same_enemies() {
+        let mut game = game_with_enemies(5);
+        game.lock_system.locked_enemies = vec![4, 2];
+        game.lock_on_lasers.spawn(homing_laser(Some(4)));
+        game.lock_on_lasers.spawn(homing_laser(Some(0)));
+        
+        game.enemies_to_remove.extend([3, 0]);
+        game.remove_enemies(false);
+        
+        // 添字が変わっても同じ敵機（x座標で識別）を指していること
+        let locked_x: Vec<f32> = game.lock_system.locked_enemies
+            .iter()
+            .map(|&idx| game.enemies[idx].position.x)
+            .collect();
+        assert_eq!(locked_x, vec![4.0, 2.0]);
+        assert_eq!(game.enemies[game.lock_on_lasers[0].target_enemy_id.unwrap()].position.x, 4.0);
+        assert_eq!(game.lock_on_lasers[1].target_enemy_id, None);
+        assert_eq!(game.enemies.len(), 3);
+        assert_eq!(game.stats.total_kills(), 0);
+    }
+    
+    #[test]
+    fn destroyed_splitters_add_drones_after_the_remaining_enemies() {
+        let mut game = game_with_enemies(4);
+        game.enemies[1].enemy_type = EnemyType::Splitter;
+        game.enemies[1].base_speed = 100.0;
+        game.lock_system.locked_enemies = vec![3, 2];
+        
+        game.enemies_to_remove.push(1);
+        game.remove_enemies(true);
+        
+        let locked_x: Vec<f32> = game.lock_system.locked_enemies
+            .iter()
+            .map(|&idx| game.enemies[idx].position.x)
+            .collect();
+        assert_eq!(locked_x, vec![3.0, 2.0]);
+        
+        // 小型機は分裂した位置から下向きに扇状に広がる
+        let drones = &game.enemies[3..];
+        assert_eq!(drones.len(), SPLIT_COUNT);
+        assert!(drones.iter().all(|drone| drone.enemy_type == EnemyType::Drone && drone.position.x == 1.0));
+        assert!(drones.iter().all(|drone| drone.velocity.y > 0.0));
+        assert!(drones[1].velocity.x.abs() < 1e-3);
+        assert!(drones[0].velocity.x.abs() > 10.0 && (drones[0].velocity.x + drones[2].velocity.x).abs() < 1e-3);
+    }
+}
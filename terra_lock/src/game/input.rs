@@ -0,0 +1,151 @@
+// 入力（1フレーム分の入力と、押下・長押し状態の管理）
+
+use std::collections::VecDeque;
+
+use macroquad::prelude::*;
+
+// 1フレーム分の入力（マウス・ボットなど入力元に依存しない）
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct InputFrame {
+    pub mouse_pos: Vec2,
+    pub left_button_down: bool,
+    pub bomb_button_down: bool, // 右クリックまたはスペースキー
+}
+
+impl InputFrame {
+    // マウスの現在状態を取得（グラフィックスコンテキストが必要）
+    pub fn capture() -> Self {
+        Self {
+            mouse_pos: mouse_position().into(),
+            left_button_down: is_mouse_button_down(MouseButton::Left),
+            bomb_button_down: is_mouse_button_down(MouseButton::Right) || is_key_down(KeyCode::Space),
+        }
+    }
+}
+
+// 入力状態管理
+#[derive(Debug)]
+pub struct InputState {
+    pub mouse_pos: Vec2,
+    pub left_button_pressed: bool,
+    pub left_button_just_pressed: bool,
+    pub left_button_just_released: bool,
+    pub left_button_hold_time: f32,
+    pub bomb_just_pressed: bool,
+    prev_left_button_pressed: bool,
+    prev_bomb_pressed: bool,
+}
+
+impl Default for InputState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl InputState {
+    pub fn new() -> Self {
+        Self {
+            mouse_pos: Vec2::ZERO,
+            left_button_pressed: false,
+            left_button_just_pressed: false,
+            left_button_just_released: false,
+            left_button_hold_time: 0.0,
+            bomb_just_pressed: false,
+            prev_left_button_pressed: false,
+            prev_bomb_pressed: false,
+        }
+    }
+    
+    pub fn apply(&mut self, frame: InputFrame, delta_time: f32) {
+        // マウス座標取得
+        self.mouse_pos = frame.mouse_pos;
+        
+        // マウスボタン状態取得
+        let current_pressed = frame.left_button_down;
+        
+        // ボタン状態の変化を検出
+        self.left_button_just_pressed = current_pressed && !self.prev_left_button_pressed;
+        self.left_button_just_released = !current_pressed && self.prev_left_button_pressed;
+        self.left_button_pressed = current_pressed;
+        
+        // 長押し時間の更新
+        if self.left_button_pressed {
+            self.left_button_hold_time += delta_time;
+        } else {
+            self.left_button_hold_time = 0.0;
+        }
+        
+        // ボムは押した瞬間のみ発動
+        self.bomb_just_pressed = frame.bomb_button_down && !self.prev_bomb_pressed;
+        
+        // 前フレームの状態を保存
+        self.prev_left_button_pressed = current_pressed;
+        self.prev_bomb_pressed = frame.bomb_button_down;
+    }
+    
+    pub fn is_long_press(&self, threshold: f32) -> bool {
+        self.left_button_hold_time >= threshold // 設定時間以上で長押し判定
+    }
+}
+
+// 描画フレームごとの入力を固定ステップへ渡すためのキュー
+// ステップが実行されない描画フレームがあってもボタンの押下・リリースを取りこぼさないよう、
+// ボタン状態が変化した入力をステップで消費されるまで古い順に保持する
+#[derive(Debug, Default)]
+pub struct InputLatch {
+    pending: VecDeque<InputFrame>,
+    last: InputFrame, // 最後に受け取った入力
+}
+
+impl InputLatch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    // 描画フレームの入力を受け取る
+    pub fn push(&mut self, frame: InputFrame) {
+        let changed = frame.left_button_down != self.last.left_button_down
+            || frame.bomb_button_down != self.last.bomb_button_down;
+        if changed {
+            self.pending.push_back(frame);
+        }
+        self.last = frame;
+    }
+    
+    // 次のステップの入力（保持中の変化があれば古い順、なければ最新の入力。マウス位置は常に最新）
+    pub fn next_step_frame(&mut self) -> InputFrame {
+        let buttons = self.pending.pop_front().unwrap_or(self.last);
+        InputFrame { mouse_pos: self.last.mouse_pos, ..buttons }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn frame(left_button_down: bool) -> InputFrame {
+        InputFrame { mouse_pos: Vec2::ZERO, left_button_down, bomb_button_down: false }
+    }
+    
+    #[test]
+    fn input_latch_keeps_a_click_made_during_frames_without_steps() {
+        let mut latch = InputLatch::new();
+        let mut input = InputState::new();
+        
+        // 押下したフレームではステップが実行されず、次のフレームでは既に離している
+        latch.push(frame(true));
+        latch.push(InputFrame { mouse_pos: Vec2::new(10.0, 20.0), ..frame(false) });
+        
+        let pressed = latch.next_step_frame();
+        assert_eq!(pressed.mouse_pos, Vec2::new(10.0, 20.0));
+        input.apply(pressed, 0.01);
+        assert!(input.left_button_just_pressed);
+        
+        input.apply(latch.next_step_frame(), 0.01);
+        assert!(input.left_button_just_released);
+        
+        // 変化がなければ最新の入力のまま
+        input.apply(latch.next_step_frame(), 0.01);
+        assert!(!input.left_button_pressed && !input.left_button_just_released);
+    }
+}
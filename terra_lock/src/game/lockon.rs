@@ -0,0 +1,547 @@
+// ロックオンシステム（長押しでワイヤーフレームを展開し、範囲内に一定時間留まった敵機をロックオン）
+// 同じ敵機にロックオンを重ねると、その数だけホーミングレーザーを発射する
+// ワイヤーフレームの展開中とレーザーの発射でエネルギーを消費し、時間経過と撃破で回復する
+
+use macroquad::prelude::*;
+
+use super::{Enemy, Game, ENEMY_POOL_CAPACITY};
+use crate::collision;
+use crate::pool;
+
+// ロックオン方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LockMode {
+    Area,    // ワイヤーフレーム内に留まった敵機をロックオン（範囲外に出ると解除）
+    Sweep,   // 長押し中にワイヤーフレームが触れた敵機を即ロックオンし、リリースまで維持
+}
+
+impl LockMode {
+    // 設定値のID（ホストページの setConfig 用）
+    pub fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(LockMode::Area),
+            1 => Some(LockMode::Sweep),
+            _ => None,
+        }
+    }
+}
+
+// 同時にロックオン時間に達した敵機の優先順（空き枠が足りない場合に使用）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LockOrder {
+    FirstIn,   // ワイヤーフレームに先に入った敵機から
+    Nearest,   // ワイヤーフレーム中心に近い敵機から
+}
+
+impl LockOrder {
+    // 設定値のID（ホストページの setConfig 用）
+    pub fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(LockOrder::FirstIn),
+            1 => Some(LockOrder::Nearest),
+            _ => None,
+        }
+    }
+}
+
+// ロックオン範囲の形状（自機・パワーアップごとに切り替え可能）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LockShape {
+    Circle,                                         // 中心から radius 以内の円
+    Cone { length: f32, half_angle: f32 },          // 自機から前方（画面上方向）へ広がる三角形
+    Band { half_height: f32 },                      // 中心の高さの画面幅いっぱいの横帯
+    GrowingCircle { start_radius: f32, growth_rate: f32 },  // 長押し時間に応じて radius まで広がる円
+}
+
+impl LockShape {
+    // 設定値のID（ホストページの setConfig 用、形状の寸法は既定値）
+    pub fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(LockShape::Circle),
+            1 => Some(LockShape::Cone { length: 320.0, half_angle: 20f32.to_radians() }),
+            2 => Some(LockShape::Band { half_height: 40.0 }),
+            3 => Some(LockShape::GrowingCircle { start_radius: 30.0, growth_rate: 150.0 }),
+            _ => None,
+        }
+    }
+}
+
+// 横帯の幅（画面幅、画面外の敵機は対象外）
+const BAND_WIDTH: f32 = 800.0;
+
+// ロックオンシステム
+#[derive(Clone, Debug)]
+pub struct LockOnSystem {
+    pub active: bool,
+    pub center: Vec2,
+    pub previous_center: Vec2,       // 前回ステップの中心（掃引判定用）
+    pub radius: f32,                 // 円の半径（拡大する円では最大半径）
+    pub shape: LockShape,
+    pub hold_time: f32,              // ワイヤーフレーム展開からの経過時間（秒）
+    pub locked_enemies: Vec<usize>,  // ロックオン中の敵機の添字（ロックオン順、重ねたロックオンは重複して並ぶ）
+    pub max_targets: u8,             // 最大ロックオン数（重ねたロックオンを含む発射レーザー数）
+    pub lock_time: f32,              // ロックオンに必要なワイヤーフレーム内の滞在時間（秒）
+    pub max_locks_per_target: u8,    // 1機に重ねられるロックオン数（耐久力の高い敵機は耐久力まで）
+    pub stack_time: f32,             // ロックオンを1つ重ねるのに必要な追加の滞在時間（秒）
+    pub order: LockOrder,
+    pub mode: LockMode,
+    pub energy: f32,                 // ロックオンエネルギー（0.0〜MAX_ENERGY）
+    pub energy_drain: f32,           // ワイヤーフレーム展開中の1秒あたりの消費量
+    pub energy_per_lock: f32,        // レーザー1本あたりの消費量（ロックオン時に残量を確認し、発射時に消費）
+    pub energy_regen: f32,           // ワイヤーフレームを閉じている間の1秒あたりの回復量
+    pub energy_per_kill: f32,        // 敵機1機の撃破ごとの回復量
+    candidates: Vec<usize>,          // ロックオン時間に達した未ロックの敵機（作業用）
+}
+
+impl Default for LockOnSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LockOnSystem {
+    pub fn new() -> Self {
+        Self {
+            active: false,
+            center: Vec2::ZERO,
+            previous_center: Vec2::ZERO,
+            radius: 100.0,
+            shape: LockShape::Circle,
+            hold_time: 0.0,
+            locked_enemies: Vec::with_capacity(ENEMY_POOL_CAPACITY), // 敵機数以上にはロックしない
+            max_targets: 6,
+            lock_time: 0.15,
+            max_locks_per_target: 1,
+            stack_time: 0.3,
+            order: LockOrder::FirstIn,
+            mode: LockMode::Area,
+            energy: Self::MAX_ENERGY,
+            energy_drain: 8.0,
+            energy_per_lock: 4.0,
+            energy_regen: 12.0,
+            energy_per_kill: 3.0,
+            candidates: Vec::with_capacity(ENEMY_POOL_CAPACITY),
+        }
+    }
+    
+    pub const MAX_ENERGY: f32 = 100.0;
+    pub const MIN_OPEN_ENERGY: f32 = 20.0;  // ワイヤーフレームの展開に必要な残量
+    
+    // ロックオンをもう1つ追加できるか（ロックオン済みの分を含む全レーザーの消費量が残量以内）
+    pub fn can_afford_lock(&self) -> bool {
+        self.energy > 0.0 && self.energy >= self.energy_per_lock * (self.locked_enemies.len() + 1) as f32
+    }
+    
+    pub fn drain_energy(&mut self, amount: f32) {
+        self.energy = (self.energy - amount).max(0.0);
+    }
+    
+    pub fn restore_energy(&mut self, amount: f32) {
+        self.energy = (self.energy + amount).min(Self::MAX_ENERGY);
+    }
+    
+    // 敵機に重ねられるロックオン数
+    pub fn stack_limit(&self, enemy: &Enemy) -> u8 {
+        self.max_locks_per_target.max(enemy.hp)
+    }
+    
+    // 次のロックオンに必要な累計滞在時間（掃引方式の最初のロックオンは0、重ねるごとに stack_time ずつ追加）
+    fn required_time(&self, lock_stack: u8) -> f32 {
+        let first_lock_time = match self.mode {
+            LockMode::Area => self.lock_time,
+            LockMode::Sweep => 0.0,
+        };
+        first_lock_time + self.stack_time * lock_stack as f32
+    }
+    
+    // 次のロックオンまでの進捗（0.0〜1.0、必要時間0の場合は常に1.0）
+    pub fn progress(&self, enemy: &Enemy) -> f32 {
+        let start = if enemy.lock_stack == 0 { 0.0 } else { self.required_time(enemy.lock_stack - 1) };
+        let duration = self.required_time(enemy.lock_stack) - start;
+        if duration <= 0.0 {
+            return 1.0;
+        }
+        ((enemy.lock_timer - start) / duration).clamp(0.0, 1.0)
+    }
+    
+    // 現在の円の半径（拡大する円は長押し時間に応じて radius まで広がる）
+    pub fn current_radius(&self) -> f32 {
+        match self.shape {
+            LockShape::GrowingCircle { start_radius, growth_rate } => {
+                (start_radius + growth_rate * self.hold_time).min(self.radius)
+            }
+            _ => self.radius,
+        }
+    }
+    
+    // 三角形の頂点（中心＝自機位置が頂点、前方は画面上方向）
+    pub fn cone_vertices(center: Vec2, length: f32, half_angle: f32) -> [Vec2; 3] {
+        let half_width = length * half_angle.tan();
+        [
+            center,
+            center + Vec2::new(-half_width, -length),
+            center + Vec2::new(half_width, -length),
+        ]
+    }
+    
+    // 中心 center の形状に位置が含まれるか
+    pub fn contains_at(&self, center: Vec2, position: Vec2) -> bool {
+        match self.shape {
+            LockShape::Circle | LockShape::GrowingCircle { .. } => {
+                position.distance_squared(center) <= self.current_radius().powi(2)
+            }
+            LockShape::Cone { length, half_angle } => {
+                collision::point_in_triangle(position, Self::cone_vertices(center, length, half_angle))
+            }
+            LockShape::Band { half_height } => {
+                (0.0..=BAND_WIDTH).contains(&position.x) && (position.y - center.y).abs() <= half_height
+            }
+        }
+    }
+    
+    // 形状を包む矩形（最小座標、最大座標、空間分割の検索範囲用）
+    pub fn bounding_rect(&self, center: Vec2) -> (Vec2, Vec2) {
+        match self.shape {
+            LockShape::Circle | LockShape::GrowingCircle { .. } => {
+                let radius = self.current_radius();
+                (center - Vec2::splat(radius), center + Vec2::splat(radius))
+            }
+            LockShape::Cone { length, half_angle } => {
+                let half_width = length * half_angle.tan();
+                (center - Vec2::new(half_width, length), center + Vec2::new(half_width, 0.0))
+            }
+            LockShape::Band { half_height } => {
+                (Vec2::new(0.0, center.y - half_height), Vec2::new(BAND_WIDTH, center.y + half_height))
+            }
+        }
+    }
+    
+    // 位置がワイヤーフレームに触れているか（掃引方式では前回ステップの中心からの移動範囲全体）
+    pub fn touches(&self, position: Vec2) -> bool {
+        match self.mode {
+            LockMode::Area => self.contains_at(self.center, position),
+            LockMode::Sweep => match self.shape {
+                LockShape::Circle | LockShape::GrowingCircle { .. } => collision::segment_intersects_circle(
+                    self.previous_center,
+                    self.center,
+                    position,
+                    self.current_radius(),
+                ),
+                // 形状が中心とともに平行移動するため、位置から見た中心の移動線分と形状の交差で判定
+                LockShape::Cone { length, half_angle } => collision::segment_intersects_triangle(
+                    position - self.previous_center,
+                    position - self.center,
+                    Self::cone_vertices(Vec2::ZERO, length, half_angle),
+                ),
+                LockShape::Band { half_height } => {
+                    let top = self.previous_center.y.min(self.center.y) - half_height;
+                    let bottom = self.previous_center.y.max(self.center.y) + half_height;
+                    (0.0..=BAND_WIDTH).contains(&position.x) && (top..=bottom).contains(&position.y)
+                }
+            },
+        }
+    }
+    
+    // ロックオン解除システム
+    pub fn clear_all_locks(&mut self, enemies: &mut [Enemy]) {
+        // 全ての敵機のロックオン状態と進捗をクリア
+        for enemy in enemies.iter_mut() {
+            enemy.is_locked = false;
+            enemy.lock_stack = 0;
+            enemy.lock_timer = 0.0;
+        }
+        
+        // ロックオンリストをクリア
+        self.locked_enemies.clear();
+        
+        // ワイヤーフレームを非表示
+        self.active = false;
+        self.hold_time = 0.0;
+        
+        game_log!("All lock-on targets cleared");
+    }
+    
+    // ワイヤーフレーム外に移動した敵機の解除
+    pub fn remove_out_of_range_targets(&mut self, enemies: &mut [Enemy]) {
+        let mut removed_count = 0;
+        
+        // 後ろから削除して、インデックスのずれを防ぐ
+        for i in (0..self.locked_enemies.len()).rev() {
+            let enemy_idx = self.locked_enemies[i];
+            
+            if enemy_idx < enemies.len() {
+                // ワイヤーフレーム外に移動した場合
+                if !self.contains_at(self.center, enemies[enemy_idx].position) {
+                    // 敵機のロックオン状態を解除（再ロックには再びロックオン時間が必要）
+                    enemies[enemy_idx].is_locked = false;
+                    enemies[enemy_idx].lock_stack = 0;
+                    enemies[enemy_idx].lock_timer = 0.0;
+                    
+                    // ロックオンリストから削除
+                    self.locked_enemies.remove(i);
+                    removed_count += 1;
+                }
+            }
+        }
+        
+        if removed_count > 0 {
+            game_log!("Removed {} targets that moved out of wireframe", removed_count);
+        }
+    }
+    
+    // 撃破された敵機のロックオン解除
+    // destroyed_indices は昇順・重複なし、enemy_count は削除前の敵機数
+    pub fn remove_destroyed_enemies(&mut self, destroyed_indices: &[usize], enemy_count: usize) {
+        if destroyed_indices.is_empty() {
+            return;
+        }
+        
+        let before_count = self.locked_enemies.len();
+        
+        // 撃破された敵機を除外し、末尾から移動する敵機のインデックスを調整
+        self.locked_enemies.retain_mut(|locked_idx| {
+            match pool::remap_after_swap_remove(*locked_idx, destroyed_indices, enemy_count) {
+                Some(adjusted_idx) => {
+                    *locked_idx = adjusted_idx;
+                    true
+                }
+                None => false,
+            }
+        });
+        
+        let removed_count = before_count - self.locked_enemies.len();
+        if removed_count > 0 {
+            game_log!("Removed {} destroyed enemies from lock-on list", removed_count);
+        }
+    }
+}
+
+impl Game {
+    pub(super) fn update_lock_on_system(&mut self, delta_time: f32) {
+        // マウス長押し検出でワイヤーフレーム展開（閉じている場合は MIN_OPEN_ENERGY まで回復するまで展開しない）
+        let can_open = self.lock_system.active || self.lock_system.energy >= LockOnSystem::MIN_OPEN_ENERGY;
+        if self.input.is_long_press(self.config.long_press_time) && can_open {
+            // 展開した最初のステップは移動なし
+            let center = self.wireframe_center();
+            self.lock_system.previous_center = if self.lock_system.active {
+                self.lock_system.hold_time += delta_time;
+                self.lock_system.center
+            } else {
+                center
+            };
+            self.lock_system.active = true;
+            self.lock_system.center = center;
+            
+            // ワイヤーフレーム外に移動した敵機の解除（掃引方式ではリリースまで維持）
+            if self.lock_system.mode == LockMode::Area {
+                self.lock_system.remove_out_of_range_targets(&mut self.enemies);
+            }
+            
+            // ワイヤーフレーム内の敵機のロックオン進捗を進め、時間に達した敵機をロックオン
+            self.acquire_locks(delta_time);
+        } else if self.input.left_button_just_released && self.lock_system.active {
+            // マウスボタンリリース時の一斉発射と完全解除
+            self.release_locks();
+        } else if !self.input.left_button_pressed {
+            // マウスボタンが押されていない場合も解除
+            if self.lock_system.active {
+                self.lock_system.clear_all_locks(&mut self.enemies);
+            }
+        }
+        
+        // 展開中はエネルギーを消費し、閉じている間は回復
+        // 展開中に尽きた場合はリリースと同様に発射して閉じる（長押し中でも MIN_OPEN_ENERGY まで回復すると再展開）
+        if self.lock_system.active {
+            self.lock_system.drain_energy(self.lock_system.energy_drain * delta_time);
+            if self.lock_system.energy <= 0.0 {
+                self.release_locks();
+            }
+        } else {
+            self.lock_system.restore_energy(self.lock_system.energy_regen * delta_time);
+        }
+    }
+    
+    // ワイヤーフレームの中心（前方三角形は自機位置を頂点とし、それ以外の形状はマウス位置）
+    fn wireframe_center(&self) -> Vec2 {
+        match self.lock_system.shape {
+            LockShape::Cone { .. } => self.player.position,
+            _ => self.input.mouse_pos,
+        }
+    }
+    
+    fn release_locks(&mut self) {
+        if !self.lock_system.locked_enemies.is_empty() {
+            // ロックオン対象がある場合は一斉発射（レーザー本数分のエネルギーを消費）
+            let laser_count = self.lock_system.locked_enemies.len();
+            self.fire_lock_on_lasers();
+            self.lock_system.drain_energy(self.lock_system.energy_per_lock * laser_count as f32);
+        }
+        self.lock_system.clear_all_locks(&mut self.enemies);
+    }
+    
+    // ロックオン済みの敵機は維持し（毎ステップ作り直さない）、新たに時間に達した敵機を末尾に追加
+    // 掃引方式ではワイヤーフレームが触れた時点でロックオン時間に達したものとする
+    // 重ねられる敵機は stack_time ごとにロックオンを追加（未ロックの敵機を優先）
+    fn acquire_locks(&mut self, delta_time: f32) {
+        let center = self.lock_system.center;
+        
+        // ワイヤーフレーム外の未ロック敵機は進捗をリセット
+        for enemy in &mut self.enemies {
+            if !enemy.is_locked && !self.lock_system.touches(enemy.position) {
+                enemy.lock_timer = 0.0;
+            }
+        }
+        
+        // ワイヤーフレーム（掃引方式では移動範囲）周辺の敵機のみ判定（添字順）
+        let (min, max) = self.lock_system.bounding_rect(center);
+        let (previous_min, previous_max) = self.lock_system.bounding_rect(self.lock_system.previous_center);
+        self.rebuild_enemy_grid();
+        self.query_buffer.clear();
+        self.enemy_grid.query_rect(min.min(previous_min), max.max(previous_max), &mut self.query_buffer);
+        self.query_buffer.sort_unstable();
+        
+        let lock_system = &mut self.lock_system;
+        lock_system.candidates.clear();
+        for &enemy_idx in &self.query_buffer {
+            let enemy = &mut self.enemies[enemy_idx];
+            if enemy.lock_stack >= lock_system.stack_limit(enemy) || !lock_system.touches(enemy.position) {
+                continue;
+            }
+            
+            enemy.lock_timer += delta_time;
+            if enemy.lock_timer >= lock_system.required_time(enemy.lock_stack) {
+                lock_system.candidates.push(enemy_idx);
+            }
+        }
+        
+        // 空き枠を優先順に割り当て（未ロックの敵機から、同順位は添字順で決定的）
+        let enemies = &self.enemies;
+        match lock_system.order {
+            LockOrder::FirstIn => lock_system.candidates.sort_unstable_by(|&a, &b| {
+                enemies[a].lock_stack.cmp(&enemies[b].lock_stack)
+                    .then(enemies[b].lock_timer.total_cmp(&enemies[a].lock_timer))
+                    .then(a.cmp(&b))
+            }),
+            LockOrder::Nearest => lock_system.candidates.sort_unstable_by(|&a, &b| {
+                let distance_a = enemies[a].position.distance_squared(center);
+                let distance_b = enemies[b].position.distance_squared(center);
+                enemies[a].lock_stack.cmp(&enemies[b].lock_stack)
+                    .then(distance_a.total_cmp(&distance_b))
+                    .then(a.cmp(&b))
+            }),
+        }
+        
+        // エネルギーが足りない場合はロックオンしない（進捗は維持し、回復後にロックオン）
+        let free_slots = (lock_system.max_targets as usize).saturating_sub(lock_system.locked_enemies.len());
+        for idx in 0..lock_system.candidates.len().min(free_slots) {
+            if !lock_system.can_afford_lock() {
+                break;
+            }
+            let enemy_idx = lock_system.candidates[idx];
+            lock_system.locked_enemies.push(enemy_idx);
+            let enemy = &mut self.enemies[enemy_idx];
+            enemy.is_locked = true;
+            enemy.lock_stack += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn remove_destroyed_enemies_drops_destroyed_and_remaps_moved_indices() {
+        let mut lock_system = LockOnSystem::new();
+        lock_system.locked_enemies = vec![0, 1, 2, 4];
+        
+        // 5機中1・3番を削除：3番の位置へ4番、1番の位置へ（移動後の）4番が入る
+        lock_system.remove_destroyed_enemies(&[1, 3], 5);
+        
+        assert_eq!(lock_system.locked_enemies, vec![0, 2, 1]);
+    }
+    
+    #[test]
+    fn can_afford_lock_counts_the_lasers_already_locked() {
+        let mut lock_system = LockOnSystem::new();
+        lock_system.energy_per_lock = 10.0;
+        lock_system.energy = 30.0;
+        lock_system.locked_enemies = vec![0, 1];
+        assert!(lock_system.can_afford_lock());
+        
+        lock_system.locked_enemies.push(2);
+        assert!(!lock_system.can_afford_lock());
+        
+        // 消費量0でも残量が空ならロックオンしない
+        lock_system.energy_per_lock = 0.0;
+        lock_system.drain_energy(100.0);
+        assert!(!lock_system.can_afford_lock());
+    }
+    
+    #[test]
+    fn remove_destroyed_enemies_ignores_empty_list() {
+        let mut lock_system = LockOnSystem::new();
+        lock_system.locked_enemies = vec![3, 1];
+        
+        lock_system.remove_destroyed_enemies(&[], 4);
+        
+        assert_eq!(lock_system.locked_enemies, vec![3, 1]);
+    }
+    
+    #[test]
+    fn remove_destroyed_enemies_handles_removing_the_last_enemy() {
+        let mut lock_system = LockOnSystem::new();
+        lock_system.locked_enemies = vec![2, 0];
+        
+        lock_system.remove_destroyed_enemies(&[2], 3);
+        
+        assert_eq!(lock_system.locked_enemies, vec![0]);
+    }
+    
+    #[test]
+    fn progress_is_the_fraction_of_lock_time_spent_in_the_wireframe() {
+        let mut game = Game::new();
+        let enemy_idx = game.spawn_enemy(crate::game::EnemyType::Straight, Vec2::ZERO, 0.0).unwrap();
+        let mut lock_system = LockOnSystem::new();
+        lock_system.lock_time = 0.2;
+        
+        game.enemies[enemy_idx].lock_timer = 0.05;
+        assert_eq!(lock_system.progress(&game.enemies[enemy_idx]), 0.25);
+        
+        game.enemies[enemy_idx].lock_timer = 0.5;
+        assert_eq!(lock_system.progress(&game.enemies[enemy_idx]), 1.0);
+        
+        lock_system.lock_time = 0.0;
+        game.enemies[enemy_idx].lock_timer = 0.0;
+        assert_eq!(lock_system.progress(&game.enemies[enemy_idx]), 1.0);
+    }
+    
+    #[test]
+    fn lock_shapes_contain_their_own_areas() {
+        let center = Vec2::new(400.0, 500.0);
+        let mut lock_system = LockOnSystem::new();
+        
+        lock_system.shape = LockShape::Cone { length: 300.0, half_angle: 20f32.to_radians() };
+        assert!(lock_system.contains_at(center, Vec2::new(400.0, 220.0)));   // 前方の遠く
+        assert!(!lock_system.contains_at(center, Vec2::new(460.0, 480.0)));  // 真横
+        assert!(!lock_system.contains_at(center, Vec2::new(400.0, 520.0)));  // 後方
+        
+        lock_system.shape = LockShape::Band { half_height: 40.0 };
+        assert!(lock_system.contains_at(center, Vec2::new(20.0, 470.0)));
+        assert!(!lock_system.contains_at(center, Vec2::new(400.0, 450.0)));
+        assert!(!lock_system.contains_at(center, Vec2::new(-20.0, 500.0)));  // 画面外
+        assert!(!lock_system.contains_at(center, Vec2::new(820.0, 500.0)));
+    }
+    
+    #[test]
+    fn growing_circle_expands_with_hold_time_up_to_radius() {
+        let mut lock_system = LockOnSystem::new();
+        lock_system.shape = LockShape::GrowingCircle { start_radius: 30.0, growth_rate: 100.0 };
+        
+        assert_eq!(lock_system.current_radius(), 30.0);
+        lock_system.hold_time = 0.5;
+        assert_eq!(lock_system.current_radius(), 80.0);
+        lock_system.hold_time = 2.0;
+        assert_eq!(lock_system.current_radius(), lock_system.radius);
+    }
+}
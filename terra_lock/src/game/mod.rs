@@ -0,0 +1,1120 @@
+// ゲームロジック
+//
+// Game がゲーム全体の状態を持ち、処理は機能ごとのサブモジュールに分ける。
+//   input    入力（1フレーム分の入力、押下・長押し判定）
+//   lockon   ロックオンシステム（ワイヤーフレーム・ロックオン対象）
+//   weapons  通常レーザー・ホーミングレーザー
+//   trajectory ホーミングレーザーの軌道（曲線・誘導）と軌跡
+//   enemies  敵機タイプ・動作パターン（射撃・分裂・発進）・自機との当たり判定
+//   spawning 敵機出現・難易度カーブ
+//   scoring  スコア・同時撃破ボーナス
+//   bomb     ボム（画面内の敵機へのダメージ・敵弾の消去・無敵時間）
+//   ships    自機の種類（性能・武装）と自機別ハイスコア
+//   render   プレイ画面の描画
+//   ui       タイトル・自機選択・ゲームオーバー画面、HUD、デバッグ表示
+// 描画は Renderer（crate::renderer）経由で行い、グラフィックスコンテキストに依存しない。
+//
+// 各サブモジュールの型は観測用に公開する（状態の変更は Game のメソッド経由）。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use macroquad::prelude::*;
+use macroquad::rand::{RandGenerator, RandomRange};
+
+use crate::bot::{
+    Controller, EnemyBulletObservation, EnemyObservation, HeuristicBot, HomingLaserObservation, LockObservation, Observation,
+};
+use crate::collision::Hitbox;
+use crate::pool::{Pool, PoolMetrics};
+use crate::renderer::{MacroquadRenderer, Renderer};
+use crate::replay::StateHasher;
+use crate::spatial::SpatialGrid;
+use crate::stats::RunStats;
+use crate::timestep::FixedTimestep;
+use crate::web_api::{self, WebCommand};
+
+// ログ出力の有効・無効（ヘッドレスシミュレーション時は無効化）
+static LOGGING: AtomicBool = AtomicBool::new(true);
+
+pub fn set_logging(enabled: bool) {
+    LOGGING.store(enabled, Ordering::Relaxed);
+}
+
+fn logging_enabled() -> bool {
+    LOGGING.load(Ordering::Relaxed)
+}
+
+macro_rules! game_log {
+    ($($arg:tt)*) => {
+        if $crate::game::logging_enabled() {
+            println!($($arg)*);
+        }
+    };
+}
+
+pub mod input;
+pub mod lockon;
+pub mod weapons;
+pub mod trajectory;
+pub mod enemies;
+pub mod spawning;
+pub mod scoring;
+pub mod bomb;
+pub mod ships;
+pub mod render;
+pub mod ui;
+#[cfg(test)]
+mod test_support;
+
+pub use bomb::BombBlast;
+pub use enemies::{Enemy, EnemyBullet, EnemyType};
+pub use input::{InputFrame, InputLatch, InputState};
+pub use lockon::{LockMode, LockOnSystem, LockOrder, LockShape};
+pub use scoring::BonusDisplay;
+use scoring::Volley;
+pub use ships::{HighScoreTable, ShipDefinition, ShotPattern, SHIPS, SHIP_COUNT};
+pub use trajectory::{Trail, Trajectory, TrajectoryMode};
+pub use weapons::{FireMode, LaserFizzle, LockOnLaser, NormalLaser, NormalWeapon, TargetLossMode};
+
+// 敵機・敵弾検索用グリッドの範囲（画面外の出現・退場位置を含む）とセルサイズ
+const ENEMY_GRID_ORIGIN: Vec2 = Vec2::new(-100.0, -150.0);
+const ENEMY_GRID_SIZE: Vec2 = Vec2::new(1000.0, 900.0);
+const ENEMY_GRID_CELL_SIZE: f32 = 64.0;
+
+// ゲーム専用の乱数生成器（Gameごとに独立し、シード指定で再現可能）
+struct GameRng(RandGenerator);
+
+impl GameRng {
+    fn new() -> Self {
+        Self(RandGenerator::new())
+    }
+    
+    fn srand(&self, seed: u64) {
+        self.0.srand(seed);
+    }
+    
+    fn gen_range<T: RandomRange>(&self, low: T, high: T) -> T {
+        self.0.gen_range(low, high)
+    }
+}
+
+impl std::fmt::Debug for GameRng {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("GameRng")
+    }
+}
+
+// 軌道用の乱数のシードを敵機出現用と分けるための値
+const TRAJECTORY_SEED_SALT: u64 = 0x9e37_79b9_7f4a_7c15;
+
+// 自機の当たり判定形状（20x15px）
+const PLAYER_HITBOX: Hitbox = Hitbox::Rect { half_size: Vec2::new(10.0, 7.5) };
+
+// オブジェクトプールの容量（超過分は生成しない）
+const ENEMY_POOL_CAPACITY: usize = 128;
+const NORMAL_LASER_POOL_CAPACITY: usize = 64;
+const LOCK_ON_LASER_POOL_CAPACITY: usize = 128;
+const BONUS_DISPLAY_POOL_CAPACITY: usize = 8;
+const LASER_FIZZLE_POOL_CAPACITY: usize = 32;
+const ENEMY_BULLET_POOL_CAPACITY: usize = 128;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GameState {
+    Title,
+    Playing,
+    GameOver,
+    Demo,      // タイトル画面放置時のデモプレイ
+    ShipSelect, // 自機選択画面（タイトルとプレイの間）
+}
+
+impl GameState {
+    // ホストページ通知用の状態ID
+    pub fn id(self) -> u32 {
+        match self {
+            GameState::Title => 0,
+            GameState::Playing => 1,
+            GameState::GameOver => 2,
+            GameState::Demo => 3,
+            GameState::ShipSelect => 4,
+        }
+    }
+}
+
+// ゲーム設定（ホストページから変更可能なパラメータ）
+#[derive(Clone, Debug)]
+pub struct GameConfig {
+    pub lock_radius: f32,           // ワイヤーフレーム半径
+    pub max_targets: u8,            // 最大ロックオン数
+    pub long_press_time: f32,       // 長押し判定時間（秒）
+    pub normal_laser_speed: f32,    // 通常レーザー速度（px/秒）
+    pub homing_initial_speed: f32,  // ホーミングレーザー初期速度
+    pub homing_acceleration: f32,   // ホーミングレーザー加速度
+    pub homing_max_speed: f32,      // ホーミングレーザー最大速度
+    pub difficulty_step_time: f32,  // 難易度上昇間隔（秒）
+    pub lock_time: f32,             // ロックオン所要時間（秒、0で即時）
+    pub lock_order: LockOrder,      // 同時にロックオン可能になった敵機の優先順
+    pub lock_mode: LockMode,        // ロックオン方式（範囲内滞在・掃引）
+    pub lock_shape: LockShape,      // ロックオン範囲の形状
+    pub max_locks_per_target: u8,   // 1機に重ねられるロックオン数
+    pub lock_stack_time: f32,       // ロックオンを1つ重ねるのに必要な追加時間（秒）
+    pub homing_trajectory: TrajectoryMode, // ホーミングレーザーの軌道の方式
+    pub homing_turn_rate: f32,      // 誘導方式の旋回速度（ラジアン/秒）
+    pub homing_target_loss: TargetLossMode, // 目標の敵機を失ったホーミングレーザーの動作
+    pub fire_mode: FireMode,        // 通常レーザーの発射方式
+    pub fire_interval: f32,         // 通常レーザーの最短発射間隔（秒）
+    pub lock_energy_drain: f32,     // ワイヤーフレーム展開中のエネルギー消費量（1秒あたり）
+    pub lock_energy_per_lock: f32,  // ホーミングレーザー1本あたりのエネルギー消費量
+    pub lock_energy_regen: f32,     // ワイヤーフレームを閉じている間のエネルギー回復量（1秒あたり）
+    pub lock_energy_per_kill: f32,  // 敵機1機の撃破ごとのエネルギー回復量
+    pub bomb_stock: u8,             // ゲーム開始時のボムの残数
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            lock_radius: 100.0,
+            max_targets: 6,
+            long_press_time: 0.2,
+            normal_laser_speed: 350.0,
+            homing_initial_speed: 140.0,
+            homing_acceleration: 560.0,
+            homing_max_speed: 560.0,
+            difficulty_step_time: 30.0,
+            lock_time: 0.15,
+            lock_order: LockOrder::FirstIn,
+            lock_mode: LockMode::Area,
+            lock_shape: LockShape::Circle,
+            max_locks_per_target: 1,
+            lock_stack_time: 0.3,
+            homing_trajectory: TrajectoryMode::Curve,
+            homing_turn_rate: 5.0,
+            homing_target_loss: TargetLossMode::Continue,
+            fire_mode: FireMode::Single,
+            fire_interval: 0.1,
+            lock_energy_drain: 8.0,
+            lock_energy_per_lock: 4.0,
+            lock_energy_regen: 12.0,
+            lock_energy_per_kill: 3.0,
+            bomb_stock: 3,
+        }
+    }
+}
+
+impl GameConfig {
+    // 設定キー一覧（JavaScript側のキーIDと同じ順序）
+    pub const KEYS: [&'static str; 24] = [
+        "lockRadius",
+        "maxTargets",
+        "longPressTime",
+        "normalLaserSpeed",
+        "homingInitialSpeed",
+        "homingAcceleration",
+        "homingMaxSpeed",
+        "difficultyStepTime",
+        "lockTime",
+        "lockOrder",
+        "lockMode",
+        "lockShape",
+        "maxLocksPerTarget",
+        "lockStackTime",
+        "homingTrajectory",
+        "homingTurnRate",
+        "homingTargetLoss",
+        "fireMode",
+        "fireInterval",
+        "lockEnergyDrain",
+        "lockEnergyPerLock",
+        "lockEnergyRegen",
+        "lockEnergyPerKill",
+        "bombStock",
+    ];
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // キー名による設定変更（不正なキー・値の場合はfalse）
+    pub fn set(&mut self, key: &str, value: f32) -> bool {
+        if !value.is_finite() || value < 0.0 {
+            return false;
+        }
+
+        match key {
+            "lockTime" => self.lock_time = value,
+            "lockOrder" => match LockOrder::from_id(value as u32) {
+                Some(order) => self.lock_order = order,  // 0: 先着順, 1: 中心に近い順
+                None => return false,
+            },
+            "lockMode" => match LockMode::from_id(value as u32) {
+                Some(mode) => self.lock_mode = mode,  // 0: 範囲内滞在, 1: 掃引
+                None => return false,
+            },
+            "lockShape" => match LockShape::from_id(value as u32) {
+                Some(shape) => self.lock_shape = shape,  // 0: 円, 1: 前方三角形, 2: 横帯, 3: 拡大する円
+                None => return false,
+            },
+            "homingTrajectory" => match TrajectoryMode::from_id(value as u32) {
+                Some(mode) => self.homing_trajectory = mode,  // 0: 曲線, 1: 誘導
+                None => return false,
+            },
+            "homingTargetLoss" => match TargetLossMode::from_id(value as u32) {
+                Some(mode) => self.homing_target_loss = mode,  // 0: 終点まで飛行, 1: 目標変更, 2: 消滅
+                None => return false,
+            },
+            "fireMode" => match FireMode::from_id(value as u32) {
+                Some(mode) => self.fire_mode = mode,  // 0: 単発, 1: 連射, 2: チャージ
+                None => return false,
+            },
+            "lockEnergyDrain" => self.lock_energy_drain = value,  // エネルギー関連は0で消費・回復なし
+            "lockEnergyPerLock" => self.lock_energy_per_lock = value,
+            "lockEnergyRegen" => self.lock_energy_regen = value,
+            "lockEnergyPerKill" => self.lock_energy_per_kill = value,
+            "bombStock" => self.bomb_stock = value.min(9.0) as u8,  // 0でボムなし（HUD表示のため最大9）
+            _ if value == 0.0 => return false,  // 以下のキーは正の値のみ
+            "lockRadius" => self.lock_radius = value,
+            "maxTargets" => self.max_targets = value.clamp(1.0, 255.0) as u8,
+            "longPressTime" => self.long_press_time = value,
+            "normalLaserSpeed" => self.normal_laser_speed = value,
+            "homingInitialSpeed" => self.homing_initial_speed = value,
+            "homingAcceleration" => self.homing_acceleration = value,
+            "homingMaxSpeed" => self.homing_max_speed = value,
+            "difficultyStepTime" => self.difficulty_step_time = value,
+            "maxLocksPerTarget" => self.max_locks_per_target = value.clamp(1.0, 255.0) as u8,
+            "lockStackTime" => self.lock_stack_time = value,
+            "homingTurnRate" => self.homing_turn_rate = value,
+            "fireInterval" => self.fire_interval = value,
+            _ => return false,
+        }
+        true
+    }
+}
+
+// ホストページへ通知するゲームイベント
+#[derive(Clone, Debug)]
+pub enum GameEvent {
+    StateChanged(GameState),
+    LocksFired(usize),
+    GameOver(RunStats),
+}
+
+// プレイヤー構造体
+#[derive(Clone, Debug)]
+pub struct Player {
+    pub position: Vec2,
+    pub previous_position: Vec2, // 前回ステップの位置（描画補間用）
+    pub hitbox: Hitbox,
+    pub health: u8,              // 仕様書準拠（HP1、被弾で即ゲームオーバー）
+    pub invulnerable_time: f32,  // 残りの無敵時間（ボム発動後）
+}
+
+impl Default for Player {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Player {
+    pub fn new() -> Self {
+        Self {
+            position: Vec2::new(400.0, 500.0),
+            previous_position: Vec2::new(400.0, 500.0),
+            hitbox: PLAYER_HITBOX,
+            health: 1,
+            invulnerable_time: 0.0,
+        }
+    }
+}
+
+// アトラクトモード（タイトル画面放置時のデモプレイ）
+#[derive(Debug)]
+struct AttractMode {
+    idle_time: f32,            // タイトル画面での無操作時間
+    demo_time: f32,            // デモプレイの経過時間
+    player_input: InputState,  // デモ中のクリック検出用（ゲーム操作はボット）
+    bot: HeuristicBot,
+}
+
+impl AttractMode {
+    const IDLE_TIME: f32 = 10.0;      // デモ開始までの無操作時間
+    const DEMO_DURATION: f32 = 40.0;  // デモプレイの最大時間
+
+    fn new() -> Self {
+        Self {
+            idle_time: 0.0,
+            demo_time: 0.0,
+            player_input: InputState::new(),
+            bot: HeuristicBot::new(),
+        }
+    }
+    
+    fn start_demo(&mut self) {
+        self.idle_time = 0.0;
+        self.demo_time = 0.0;
+        self.player_input = InputState::new();
+        self.bot.reset();
+    }
+}
+
+// メインゲーム構造体
+#[derive(Debug)]
+pub struct Game {
+    state: GameState,
+    player: Player,
+    enemies: Pool<Enemy>,
+    enemy_bullets: Pool<EnemyBullet>,
+    normal_lasers: Pool<NormalLaser>,
+    weapon: NormalWeapon,   // 通常レーザーの発射装置（発射間隔・熱・チャージ）
+    lock_on_lasers: Pool<LockOnLaser>,
+    volleys: Vec<Volley>,   // 飛行中の一斉発射（同時撃破ボーナス判定用）
+    next_volley_id: u32,
+    lock_system: LockOnSystem,
+    score: u32,
+    input: InputState,
+    input_latch: InputLatch, // 描画フレームの入力を固定ステップが消費するまで保持
+    enemy_spawn_timer: f32,
+    bonus_displays: Pool<BonusDisplay>,
+    laser_fizzles: Pool<LaserFizzle>, // 命中しなかったホーミングレーザーの消滅エフェクト
+    bombs: u8,              // ボムの残数
+    bomb_blast: Option<BombBlast>, // ボムの画面全体のエフェクト
+    game_start_time: f32,  // ゲーム開始時刻（難易度カーブ用）
+    config: GameConfig,
+    config_overrides: Vec<(&'static str, f32)>, // set_config で変更した項目（自機選択時に自機の性能より優先）
+    events: Vec<GameEvent>, // ホストページ通知待ちイベント
+    stats: RunStats,        // プレイ統計
+    time: f32,              // ゲーム内経過時刻（update毎に加算、ヘッドレス実行対応）
+    attract: AttractMode,   // タイトル画面のデモプレイ
+    enemy_grid: SpatialGrid, // 敵機の空間分割（当たり判定・ロックオン判定用）
+    bullet_grid: SpatialGrid, // 敵弾の空間分割（自機との当たり判定用）
+    query_buffer: Vec<usize>, // グリッド検索結果の再利用バッファ
+    enemies_to_remove: Vec<usize>, // 削除対象の敵機（再利用バッファ）
+    enemy_spawn_queue: Vec<(EnemyType, Vec2, Vec2)>, // 生成待ちの敵機（タイプ、位置、速度、再利用バッファ）
+    lasers_to_remove: Vec<usize>,  // 削除対象の通常レーザー（再利用バッファ）
+    observation: Observation,      // ボット用観測情報の再利用バッファ
+    timestep: FixedTimestep,       // 固定タイムステップ（描画補間の係数を含む）
+    enemy_spawning: bool,          // 敵機の自動出現（テスト等で無効化）
+    rng: GameRng,                  // 敵機出現用の乱数（シード未指定時は前回のゲームから継続）
+    trajectory_rng: GameRng,       // ホーミングレーザーの軌道の揺らぎ用の乱数（敵機出現の乱数列に影響しない）
+    ship: usize,                   // 選択中の自機（SHIPS の添字）
+    high_scores: [HighScoreTable; SHIP_COUNT], // 自機別ハイスコア（セッション中のみ保持）
+}
+
+impl Default for Game {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Game {
+    pub fn new() -> Self {
+        let mut game = Self {
+            state: GameState::Title, // タイトル画面から開始
+            player: Player::new(),
+            enemies: Pool::with_capacity(ENEMY_POOL_CAPACITY),
+            enemy_bullets: Pool::with_capacity(ENEMY_BULLET_POOL_CAPACITY),
+            normal_lasers: Pool::with_capacity(NORMAL_LASER_POOL_CAPACITY),
+            weapon: NormalWeapon::new(),
+            lock_on_lasers: Pool::with_capacity(LOCK_ON_LASER_POOL_CAPACITY),
+            volleys: Vec::with_capacity(LOCK_ON_LASER_POOL_CAPACITY),
+            next_volley_id: 0,
+            lock_system: LockOnSystem::new(),
+            score: 0,
+            input: InputState::new(),
+            input_latch: InputLatch::new(),
+            enemy_spawn_timer: 0.0,
+            bonus_displays: Pool::with_capacity(BONUS_DISPLAY_POOL_CAPACITY),
+            laser_fizzles: Pool::with_capacity(LASER_FIZZLE_POOL_CAPACITY),
+            bombs: 0,
+            bomb_blast: None,
+            game_start_time: 0.0,
+            config: GameConfig::new(),
+            config_overrides: Vec::with_capacity(GameConfig::KEYS.len()),
+            events: Vec::with_capacity(16),
+            stats: RunStats::new(),
+            time: 0.0,
+            attract: AttractMode::new(),
+            enemy_grid: SpatialGrid::new(
+                ENEMY_GRID_ORIGIN,
+                ENEMY_GRID_SIZE.x,
+                ENEMY_GRID_SIZE.y,
+                ENEMY_GRID_CELL_SIZE,
+                ENEMY_POOL_CAPACITY,
+            ),
+            bullet_grid: SpatialGrid::new(
+                ENEMY_GRID_ORIGIN,
+                ENEMY_GRID_SIZE.x,
+                ENEMY_GRID_SIZE.y,
+                ENEMY_GRID_CELL_SIZE,
+                ENEMY_BULLET_POOL_CAPACITY,
+            ),
+            query_buffer: Vec::with_capacity(ENEMY_POOL_CAPACITY),
+            enemies_to_remove: Vec::with_capacity(ENEMY_POOL_CAPACITY),
+            enemy_spawn_queue: Vec::with_capacity(ENEMY_POOL_CAPACITY),
+            lasers_to_remove: Vec::with_capacity(NORMAL_LASER_POOL_CAPACITY),
+            timestep: FixedTimestep::default(),
+            enemy_spawning: true,
+            rng: GameRng::new(),
+            trajectory_rng: GameRng::new(),
+            ship: 0,
+            high_scores: std::array::from_fn(|_| HighScoreTable::new()),
+            observation: Observation {
+                enemies: Vec::with_capacity(ENEMY_POOL_CAPACITY),
+                enemy_bullets: Vec::with_capacity(ENEMY_BULLET_POOL_CAPACITY),
+                normal_lasers: Vec::with_capacity(NORMAL_LASER_POOL_CAPACITY),
+                homing_lasers: Vec::with_capacity(LOCK_ON_LASER_POOL_CAPACITY),
+                ..Observation::default()
+            },
+        };
+        
+        // テスト用敵機を追加（描画確認用）
+        game.enemies.spawn(Enemy {
+            position: Vec2::new(200.0, 100.0),
+            previous_position: Vec2::new(200.0, 100.0),
+            hitbox: EnemyType::Straight.hitbox(),
+            velocity: Vec2::new(0.0, 120.0), // 120px/秒で下向き
+            is_locked: false,
+            lock_stack: 0,
+            lock_timer: 0.0,
+            hp: EnemyType::Straight.hit_points(),
+            enemy_type: EnemyType::Straight,
+            spawn_time: game.time,
+            base_speed: 120.0,
+            hover_time: 0.0,
+            action_timer: 0.0,
+            charging: false,
+        });
+        
+        game
+    }
+    
+    // 1描画フレーム分の更新（フレーム時間を固定ステップに分割して実行）
+    fn update(&mut self, frame_time: f32) {
+        // 統計のJSON出力（ゲームオーバー画面でEキー）
+        if self.state == GameState::GameOver && is_key_pressed(KeyCode::E) {
+            self.export_stats();
+        }
+        
+        // ステップが実行されないフレームの押下・リリースは次に実行されるステップまで保持
+        self.input_latch.push(InputFrame::capture());
+        let steps = self.timestep.advance(frame_time);
+        for _ in 0..steps {
+            let frame = self.input_latch.next_step_frame();
+            self.step(self.timestep.step(), frame);
+        }
+    }
+    
+    // 入力を指定して1フレーム進める（グラフィックスコンテキスト不要）
+    pub fn step(&mut self, delta_time: f32, frame: InputFrame) {
+        self.time += delta_time;
+        
+        // デモプレイ中はボットの入力で進行
+        if self.state == GameState::Demo {
+            self.update_demo(delta_time, frame);
+            return;
+        }
+        
+        // 一定時間操作がなければデモプレイへ（マウス移動・クリック・ボムで延長）
+        if frame.mouse_pos != self.input.mouse_pos || frame.left_button_down || frame.bomb_button_down {
+            self.attract.idle_time = 0.0;
+        }
+        
+        // 入力状態更新
+        self.input.apply(frame, delta_time);
+        
+        // ゲーム状態に応じた処理分岐
+        match self.state {
+            GameState::Title => {
+                // タイトル画面での入力処理
+                if self.input.left_button_just_pressed {
+                    self.set_state(GameState::ShipSelect);
+                } else {
+                    self.attract.idle_time += delta_time;
+                    if self.attract.idle_time >= AttractMode::IDLE_TIME {
+                        self.start_demo();
+                    }
+                }
+            }
+            GameState::Playing => {
+                self.update_playing(delta_time);
+            }
+            GameState::Demo => {}
+            GameState::ShipSelect => {
+                // クリックした自機で出撃
+                if self.input.left_button_just_pressed {
+                    if let Some(ship) = ui::ship_panel_at(self.input.mouse_pos) {
+                        self.select_ship(ship);
+                        self.start_game();
+                    }
+                }
+            }
+            GameState::GameOver => {
+                // ゲームオーバー状態でのリスタート処理
+                if self.input.left_button_just_pressed {
+                    self.restart_game();
+                }
+            }
+        }
+    }
+    
+    fn update_demo(&mut self, delta_time: f32, frame: InputFrame) {
+        // プレイヤーのクリックでタイトル画面へ戻る
+        self.attract.player_input.apply(frame, delta_time);
+        if self.attract.player_input.left_button_just_pressed {
+            self.end_demo();
+            return;
+        }
+        
+        let observation = self.take_observation();
+        let bot_frame = self.attract.bot.next_input(&observation, delta_time);
+        self.observation = observation;
+        self.input.apply(bot_frame, delta_time);
+        self.update_playing(delta_time);
+        
+        // 規定時間経過でタイトル画面へ戻る（被弾時は当たり判定で終了）
+        self.attract.demo_time += delta_time;
+        if self.state == GameState::Demo && self.attract.demo_time >= AttractMode::DEMO_DURATION {
+            self.end_demo();
+        }
+    }
+    
+    // 状態遷移（ホストページへ通知）
+    fn set_state(&mut self, state: GameState) {
+        if self.state != state {
+            self.state = state;
+            self.events.push(GameEvent::StateChanged(state));
+        }
+    }
+    
+    // イベント追加（デモプレイ中の結果はホストページへ通知しない）
+    fn emit(&mut self, event: GameEvent) {
+        if self.state != GameState::Demo {
+            self.events.push(event);
+        }
+    }
+    
+    // 通知待ちイベントの取り出し
+    pub fn drain_events(&mut self) -> std::vec::Drain<'_, GameEvent> {
+        self.events.drain(..)
+    }
+    
+    // ゲーム設定の変更（ロックオンシステムへ即時反映）
+    pub fn set_config(&mut self, key: &str, value: f32) -> bool {
+        let applied = self.config.set(key, value);
+        if applied {
+            self.record_config_override(key, value);
+            self.apply_lock_config();
+            game_log!("Config updated: {} = {}", key, value);
+        }
+        applied
+    }
+    
+    // 変更した項目を記録（同じキーは最後の値のみ保持）
+    fn record_config_override(&mut self, key: &str, value: f32) {
+        let Some(&key) = GameConfig::KEYS.iter().find(|&&known| known == key) else {
+            return;
+        };
+        match self.config_overrides.iter_mut().find(|(overridden, _)| *overridden == key) {
+            Some(entry) => entry.1 = value,
+            None => self.config_overrides.push((key, value)),
+        }
+    }
+    
+    fn apply_lock_config(&mut self) {
+        self.lock_system.radius = self.config.lock_radius;
+        self.lock_system.max_targets = self.config.max_targets;
+        self.lock_system.lock_time = self.config.lock_time;
+        self.lock_system.order = self.config.lock_order;
+        self.lock_system.mode = self.config.lock_mode;
+        self.lock_system.shape = self.config.lock_shape;
+        self.lock_system.max_locks_per_target = self.config.max_locks_per_target;
+        self.lock_system.stack_time = self.config.lock_stack_time;
+        self.lock_system.energy_drain = self.config.lock_energy_drain;
+        self.lock_system.energy_per_lock = self.config.lock_energy_per_lock;
+        self.lock_system.energy_regen = self.config.lock_energy_regen;
+        self.lock_system.energy_per_kill = self.config.lock_energy_per_kill;
+    }
+    
+    // 自機の選択（武装・ロックオン性能を設定に反映、次のゲーム開始から有効）
+    // set_config で変更した項目は、選択の前後にかかわらず自機の性能より優先される
+    pub fn select_ship(&mut self, ship: usize) -> bool {
+        let Some(definition) = SHIPS.get(ship) else {
+            return false;
+        };
+        
+        self.ship = ship;
+        self.config.normal_laser_speed = definition.normal_laser_speed;
+        self.config.lock_shape = definition.lock_shape;
+        self.config.lock_radius = definition.lock_radius;
+        self.config.max_targets = definition.max_targets;
+        self.config.homing_initial_speed = definition.homing_initial_speed;
+        self.config.homing_acceleration = definition.homing_acceleration;
+        self.config.homing_max_speed = definition.homing_max_speed;
+        for &(key, value) in &self.config_overrides {
+            self.config.set(key, value);
+        }
+        self.apply_lock_config();
+        game_log!("Ship selected: {}", definition.name);
+        true
+    }
+    
+    pub fn ship(&self) -> &'static ShipDefinition {
+        &SHIPS[self.ship]
+    }
+    
+    // 自機別ハイスコア
+    pub fn high_scores(&self, ship: usize) -> &HighScoreTable {
+        &self.high_scores[ship]
+    }
+    
+    // シード指定でゲーム開始（タイトル・ゲームオーバーどちらからでも可）
+    pub fn start_game_with_seed(&mut self, seed: u64) {
+        self.rng.srand(seed);
+        self.trajectory_rng.srand(seed ^ TRAJECTORY_SEED_SALT);
+        self.start_game();
+        game_log!("Seed: {}", seed);
+    }
+    
+    pub fn state(&self) -> GameState {
+        self.state
+    }
+    
+    pub fn score(&self) -> u32 {
+        self.score
+    }
+    
+    pub fn stats(&self) -> &RunStats {
+        &self.stats
+    }
+    
+    pub fn player_position(&self) -> Vec2 {
+        self.player.position
+    }
+    
+    pub fn locked_count(&self) -> usize {
+        self.lock_system.locked_enemies.len()
+    }
+    
+    // ロックオン中の敵機（observe().enemies の添字、ロックオン順）
+    pub fn locked_enemies(&self) -> &[usize] {
+        &self.lock_system.locked_enemies
+    }
+    
+    pub fn enemy_count(&self) -> usize {
+        self.enemies.len()
+    }
+    
+    // エンティティの参照（ツール・ボット用、変更は Game のメソッド経由）
+    pub fn player(&self) -> &Player {
+        &self.player
+    }
+    
+    pub fn enemies(&self) -> &[Enemy] {
+        &self.enemies
+    }
+    
+    pub fn enemy_bullets(&self) -> &[EnemyBullet] {
+        &self.enemy_bullets
+    }
+    
+    pub fn normal_lasers(&self) -> &[NormalLaser] {
+        &self.normal_lasers
+    }
+    
+    pub fn homing_lasers(&self) -> &[LockOnLaser] {
+        &self.lock_on_lasers
+    }
+    
+    pub fn weapon(&self) -> &NormalWeapon {
+        &self.weapon
+    }
+    
+    pub fn lock_system(&self) -> &LockOnSystem {
+        &self.lock_system
+    }
+    
+    pub fn bonus_displays(&self) -> &[BonusDisplay] {
+        &self.bonus_displays
+    }
+    
+    pub fn laser_fizzles(&self) -> &[LaserFizzle] {
+        &self.laser_fizzles
+    }
+    
+    pub fn bombs(&self) -> u8 {
+        self.bombs
+    }
+    
+    pub fn bomb_blast(&self) -> Option<&BombBlast> {
+        self.bomb_blast.as_ref()
+    }
+    
+    // ゲーム内経過時刻（ゲーム開始からの秒数）
+    pub fn elapsed_time(&self) -> f32 {
+        self.time - self.game_start_time
+    }
+    
+    // ゲーム状態のハッシュ値（リプレイの回帰テスト用、Rustのバージョンに依存しない）
+    pub fn state_hash(&self) -> u64 {
+        let mut hasher = StateHasher::new();
+        hasher.write_u32(self.state.id());
+        hasher.write_u32(self.score);
+        hasher.write_f32(self.time - self.game_start_time);
+        hasher.write_vec2(self.player.position);
+        hasher.write_f32(self.player.invulnerable_time);
+        hasher.write_u32(self.bombs as u32);
+        
+        hasher.write_usize(self.enemies.len());
+        for enemy in &self.enemies {
+            hasher.write_usize(enemy.enemy_type.index());
+            hasher.write_vec2(enemy.position);
+            hasher.write_vec2(enemy.velocity);
+            hasher.write_bool(enemy.is_locked);
+            hasher.write_u32(enemy.lock_stack as u32);
+            hasher.write_f32(enemy.lock_timer);
+            hasher.write_u32(enemy.hp as u32);
+            hasher.write_f32(enemy.hover_time);
+            hasher.write_f32(enemy.action_timer);
+            hasher.write_bool(enemy.charging);
+        }
+        
+        hasher.write_usize(self.enemy_bullets.len());
+        for bullet in &self.enemy_bullets {
+            hasher.write_vec2(bullet.position);
+        }
+        
+        hasher.write_usize(self.normal_lasers.len());
+        for laser in &self.normal_lasers {
+            hasher.write_vec2(laser.position);
+            hasher.write_u32(laser.pierce as u32);
+        }
+        hasher.write_f32(self.weapon.cooldown);
+        hasher.write_f32(self.weapon.heat);
+        hasher.write_f32(self.weapon.charge);
+        
+        hasher.write_usize(self.lock_on_lasers.len());
+        for laser in &self.lock_on_lasers {
+            hasher.write_vec2(laser.position);
+            hasher.write_vec2(laser.target_pos);
+            hasher.write_f32(laser.progress);
+            hasher.write_usize(laser.target_enemy_id.map_or(usize::MAX, |id| id));
+        }
+        
+        hasher.write_usize(self.lock_system.locked_enemies.len());
+        for &enemy_idx in &self.lock_system.locked_enemies {
+            hasher.write_usize(enemy_idx);
+        }
+        hasher.write_f32(self.lock_system.energy);
+        
+        for &kills in &self.stats.kills_by_type {
+            hasher.write_u32(kills);
+        }
+        hasher.finish()
+    }
+    
+    // 敵機の自動出現の有効・無効（spawn_enemy で配置した敵機のみで検証する場合に無効化）
+    pub fn set_enemy_spawning(&mut self, enabled: bool) {
+        self.enemy_spawning = enabled;
+    }
+    
+    pub fn config(&self) -> &GameConfig {
+        &self.config
+    }
+    
+    // ボット用の観測情報を生成
+    pub fn observe(&self) -> Observation {
+        let mut observation = Observation::default();
+        self.observe_into(&mut observation);
+        observation
+    }
+    
+    // 既存の観測情報のバッファを再利用して上書き
+    pub fn observe_into(&self, observation: &mut Observation) {
+        observation.time = self.time - self.game_start_time;
+        observation.player_position = self.player.position;
+        
+        observation.enemies.clear();
+        observation.enemies.extend(self.enemies.iter().map(|enemy| EnemyObservation {
+            position: enemy.position,
+            velocity: enemy.velocity,
+            enemy_type: enemy.enemy_type,
+            is_locked: enemy.is_locked,
+        }));
+        
+        observation.enemy_bullets.clear();
+        observation.enemy_bullets.extend(self.enemy_bullets.iter().map(|bullet| EnemyBulletObservation {
+            position: bullet.position,
+            velocity: bullet.velocity,
+        }));
+        
+        observation.normal_lasers.clear();
+        observation.normal_lasers.extend(self.normal_lasers.iter().map(|laser| laser.position));
+        
+        observation.homing_lasers.clear();
+        observation.homing_lasers.extend(self.lock_on_lasers.iter().map(|laser| HomingLaserObservation {
+            position: laser.head(),
+            target_position: laser.target_pos,
+            progress: laser.progress,
+        }));
+        
+        observation.lock = LockObservation {
+            active: self.lock_system.active,
+            center: self.lock_system.center,
+            radius: self.lock_system.current_radius(),
+            locked_count: self.lock_system.locked_enemies.len(),
+            max_targets: self.lock_system.max_targets as usize,
+            hold_time: self.input.left_button_hold_time,
+            long_press_time: self.config.long_press_time,
+            energy: self.lock_system.energy,
+            energy_per_lock: self.lock_system.energy_per_lock,
+        };
+    }
+    
+    // 観測情報バッファを更新して取り出す（使用後は self.observation に戻す）
+    fn take_observation(&mut self) -> Observation {
+        let mut observation = std::mem::take(&mut self.observation);
+        self.observe_into(&mut observation);
+        observation
+    }
+    
+    // コントローラーの入力で1フレーム進める
+    pub fn step_with_controller(&mut self, delta_time: f32, controller: &mut dyn Controller) {
+        let observation = self.take_observation();
+        let frame = controller.next_input(&observation, delta_time);
+        self.observation = observation;
+        self.step(delta_time, frame);
+    }
+    
+    // オブジェクトプールの使用状況（敵機、敵弾、通常レーザー、ホーミングレーザー、ボーナス表示、消滅エフェクト）
+    pub fn pool_metrics(&self) -> [(&'static str, PoolMetrics); 6] {
+        [
+            ("Enemies", self.enemies.metrics()),
+            ("Bullets", self.enemy_bullets.metrics()),
+            ("Lasers", self.normal_lasers.metrics()),
+            ("Homing", self.lock_on_lasers.metrics()),
+            ("Bonus", self.bonus_displays.metrics()),
+            ("Fizzle", self.laser_fizzles.metrics()),
+        ]
+    }
+    
+    // プレイ画面の初期化（ゲーム開始・リスタート・デモ開始で共通）
+    fn reset_play_field(&mut self) {
+        self.score = 0;
+        self.enemy_spawn_timer = 0.0;
+        self.game_start_time = self.time;  // ゲーム開始時刻をリセット
+        self.stats = RunStats::new();
+        
+        // プレイヤーを初期状態に設定（当たり判定は選択中の自機）
+        self.player = Player::new();
+        self.player.hitbox = self.ship().hitbox;
+        self.stats.ship = self.ship().name;
+        
+        // 全てのオブジェクトをクリア
+        self.enemies.clear();
+        self.enemy_bullets.clear();
+        self.normal_lasers.clear();
+        self.weapon = NormalWeapon::new();
+        self.lock_on_lasers.clear();
+        self.volleys.clear();
+        self.next_volley_id = 0;
+        self.bonus_displays.clear();
+        self.laser_fizzles.clear();
+        
+        // ボムの残数を補充
+        self.bombs = self.config.bomb_stock;
+        self.bomb_blast = None;
+        
+        // ロックオンシステムをリセット
+        self.lock_system.active = false;
+        self.lock_system.hold_time = 0.0;
+        self.lock_system.locked_enemies.clear();
+        self.lock_system.energy = LockOnSystem::MAX_ENERGY;
+    }
+    
+    fn start_game(&mut self) {
+        // タイトル画面からゲーム開始
+        self.set_state(GameState::Playing);
+        self.reset_play_field();
+        
+        game_log!("Game Started!");
+    }
+    
+    fn restart_game(&mut self) {
+        // ゲーム状態をリセット
+        self.set_state(GameState::Playing);
+        self.reset_play_field();
+        
+        game_log!("Game Restarted!");
+    }
+    
+    fn start_demo(&mut self) {
+        // タイトル画面放置でデモプレイ開始
+        self.set_state(GameState::Demo);
+        self.reset_play_field();
+        self.attract.start_demo();
+        
+        game_log!("Demo Started!");
+    }
+    
+    fn end_demo(&mut self) {
+        // デモプレイ終了でタイトル画面へ戻る
+        self.set_state(GameState::Title);
+        self.reset_play_field();
+        self.attract.idle_time = 0.0;
+        
+        game_log!("Demo Ended!");
+    }
+    
+    // 描画補間用に現在位置を保存（ステップ開始時）
+    fn store_previous_positions(&mut self) {
+        self.player.previous_position = self.player.position;
+        for enemy in &mut self.enemies {
+            enemy.previous_position = enemy.position;
+        }
+        for laser in &mut self.normal_lasers {
+            laser.previous_position = laser.position;
+        }
+        for laser in &mut self.lock_on_lasers {
+            laser.previous_position = laser.position;
+            laser.previous_progress = laser.progress;
+            laser.previous_target_pos = laser.target_pos;
+        }
+        for bonus_display in &mut self.bonus_displays {
+            bonus_display.previous_position = bonus_display.position;
+        }
+    }
+    
+    fn update_playing(&mut self, delta_time: f32) {
+        self.store_previous_positions();
+        
+        // プレイヤーの位置をマウス座標に更新（画面内制限付き、自機の速度で追従）
+        let screen_width = 800.0;
+        let screen_height = 600.0;
+        let player_half_size = match self.player.hitbox {
+            Hitbox::Rect { half_size } => half_size,  // 既定の自機は20x15px
+            Hitbox::Circle { radius } => Vec2::splat(radius),
+        };
+        let player_half_width = player_half_size.x;
+        let player_half_height = player_half_size.y;
+        
+        // マウス座標を画面内に制限
+        let clamped_x = self.input.mouse_pos.x.clamp(
+            player_half_width, 
+            screen_width - player_half_width
+        );
+        let clamped_y = self.input.mouse_pos.y.clamp(
+            player_half_height, 
+            screen_height - player_half_height
+        );
+        
+        let target = Vec2::new(clamped_x, clamped_y);
+        let max_distance = self.ship().speed * delta_time;
+        self.player.position = if self.player.position.distance(target) <= max_distance {
+            target
+        } else {
+            self.player.position + (target - self.player.position).normalize() * max_distance
+        };
+        
+        // ロックオンシステムの更新
+        self.update_lock_on_system(delta_time);
+        
+        // 通常レーザーの発射（発射方式に応じて短押し・連射・チャージ）
+        self.update_normal_weapon(delta_time);
+        
+        // ボム（右クリック・スペースキー、画面内の敵機へのダメージと無敵時間）
+        self.update_bomb(delta_time);
+        
+        // 敵機出現システム（難易度カーブ対応）
+        self.update_spawning(delta_time);
+        
+        // 統計の更新（生存時間・到達難易度）
+        self.stats.time_survived = self.time - self.game_start_time;
+        self.stats.difficulty_level = self.stats.difficulty_level.max(self.difficulty_level());
+        
+        // 通常レーザーの更新
+        self.update_normal_lasers(delta_time);
+        
+        // ホーミングレーザーの更新（動的ターゲット追跡 + 加速、命中した敵機の撃破）
+        self.update_homing_lasers(delta_time);
+        
+        // 敵機の更新（タイプ別動作パターン、射撃・小型機の発進）
+        self.update_enemies(delta_time);
+        
+        // 敵弾の更新
+        self.update_enemy_bullets(delta_time);
+        
+        // レーザーと敵機の当たり判定
+        self.check_laser_enemy_collision();
+        
+        // 敵機・敵弾と自機の当たり判定
+        self.check_player_enemy_collision();
+        
+        // 画面外の敵機を削除（ロックオン・ホーミングレーザーの参照も調整）
+        self.remove_offscreen_enemies();
+        
+        // ボーナス表示の更新
+        self.update_bonus_displays(delta_time);
+        
+        // ホーミングレーザーの消滅エフェクトの更新
+        self.update_laser_fizzles(delta_time);
+        
+        // TODO: その他のゲームロジックの更新
+    }
+}
+
+// WebAssembly対応のメイン関数
+pub async fn main() {
+    // 画面サイズ設定（800x600px）
+    request_new_screen_size(800.0, 600.0);
+    
+    // ゲーム状態の初期化
+    let mut game = Game::new();
+    let mut renderer = MacroquadRenderer;
+    
+    // FPS計測用変数
+    let mut frame_count = 0;
+    let mut last_time = get_time();
+    let mut fps_display = 60.0;
+    let mut last_frame_time = get_time();
+    
+    loop {
+        renderer.clear_background(BLACK);
+        
+        // デルタタイム計算
+        let current_time = get_time();
+        let delta_time = (current_time - last_frame_time) as f32;
+        last_frame_time = current_time;
+        
+        // ホストページからのコマンド処理
+        for command in web_api::take_commands() {
+            match command {
+                WebCommand::SetConfig(key, value) => {
+                    game.set_config(key, value);
+                }
+                WebCommand::Start(seed) => game.start_game_with_seed(seed),
+            }
+        }
+        
+        // ゲーム更新（固定タイムステップ）
+        game.update(delta_time);
+        
+        // ホストページへのイベント通知
+        for event in game.drain_events() {
+            web_api::notify(&event);
+        }
+        
+        // ゲーム描画
+        game.draw(&mut renderer);
+        
+        // FPS計算と表示
+        frame_count += 1;
+        if current_time - last_time >= 1.0 {
+            fps_display = frame_count as f32 / (current_time - last_time) as f32;
+            frame_count = 0;
+            last_time = current_time;
+        }
+        
+        // デバッグ情報表示
+        game.draw_debug_info(&mut renderer, fps_display);
+        
+        next_frame().await;
+    }
+}
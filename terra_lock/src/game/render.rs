@@ -0,0 +1,334 @@
+// プレイ画面の描画（自機・敵機・敵弾・レーザー・ワイヤーフレーム）
+//
+// 前回ステップと今回ステップの位置を描画補間係数で補間して描画する。
+
+use macroquad::prelude::*;
+
+use crate::collision::Hitbox;
+use crate::renderer::Renderer;
+
+use super::trajectory::TRAIL_LENGTH;
+use super::{Enemy, EnemyType, Game, GameState, LockMode, LockOnSystem, LockShape};
+
+// ホーミングレーザーの帯の先端の幅（尾に向かって0まで細くなる）
+const HOMING_RIBBON_WIDTH: f32 = 4.0;
+
+// ホーミングレーザーの消滅エフェクトの火花の本数と、広がる距離
+const FIZZLE_SPARKS: usize = 8;
+const FIZZLE_SPREAD: f32 = 12.0;
+
+// ボムの衝撃波の最大半径（画面の対角線より大きく）
+const BOMB_WAVE_RADIUS: f32 = 1000.0;
+const BOMB_WAVE_SEGMENTS: usize = 48;
+
+// シールド型の外周の線分数
+const SHIELD_SEGMENTS: usize = 16;
+
+impl Game {
+    // 現在の状態の画面を描画（グラフィックスコンテキストが必要なのは MacroquadRenderer のみ）
+    pub fn draw(&self, renderer: &mut dyn Renderer) {
+        // ゲーム状態に応じた描画処理
+        match self.state {
+            GameState::Title => {
+                self.draw_title(renderer);
+            }
+            GameState::Playing => {
+                self.draw_playing(renderer);
+            }
+            GameState::Demo => {
+                self.draw_playing(renderer);
+                self.draw_demo_overlay(renderer);
+            }
+            GameState::ShipSelect => {
+                self.draw_ship_select(renderer);
+            }
+            GameState::GameOver => {
+                self.draw_game_over(renderer);
+            }
+        }
+    }
+    
+    fn draw_playing(&self, renderer: &mut dyn Renderer) {
+        // 前回ステップと今回ステップの間を補間して描画
+        let alpha = self.timestep.alpha();
+        
+        // プレイヤー（自機）の描画 - 自機の色の三角形（当たり判定と同じ大きさ、既定は青の20x15px）
+        let player_pos = self.player.previous_position.lerp(self.player.position, alpha);
+        let (width, height) = match self.player.hitbox {
+            Hitbox::Rect { half_size } => (half_size.x * 2.0, half_size.y * 2.0),
+            Hitbox::Circle { radius } => (radius * 2.0, radius * 2.0),
+        };
+        let vertices = [
+            Vec2::new(player_pos.x, player_pos.y - height / 2.0),                    // 上頂点
+            Vec2::new(player_pos.x - width / 2.0, player_pos.y + height / 2.0),     // 左下
+            Vec2::new(player_pos.x + width / 2.0, player_pos.y + height / 2.0),     // 右下
+        ];
+        // 無敵時間中は点滅
+        let blink_hidden = self.player.invulnerable_time > 0.0 && (self.time * 20.0).sin() < 0.0;
+        if !blink_hidden {
+            renderer.draw_triangle(vertices[0], vertices[1], vertices[2], self.ship().color);
+        }
+        
+        // 敵機の描画 - タイプ別の色と形状、ロックオン時は黄色
+        for enemy in &self.enemies {
+            let base_color = if enemy.is_locked {
+                YELLOW
+            } else {
+                match enemy.enemy_type {
+                    EnemyType::Straight => RED,           // 直線: 赤
+                    EnemyType::Zigzag => ORANGE,          // ジグザグ: オレンジ
+                    EnemyType::Arc => PURPLE,             // 円弧: 紫
+                    EnemyType::Homing => Color::new(1.0, 0.0, 0.5, 1.0), // 追尾: ピンク
+                    EnemyType::Shooter => Color::new(0.4, 1.0, 0.2, 1.0),  // 射撃: 黄緑
+                    EnemyType::Splitter => Color::new(0.0, 0.8, 0.7, 1.0), // 分裂: 青緑
+                    EnemyType::Shielded => Color::new(0.4, 0.5, 1.0, 1.0), // シールド: 青
+                    EnemyType::Kamikaze => Color::new(1.0, 0.35, 0.0, 1.0), // 自爆: 朱色
+                    EnemyType::Carrier => Color::new(0.55, 0.6, 0.7, 1.0), // 母艦: 灰青
+                    EnemyType::Drone => Color::new(0.85, 0.85, 0.85, 1.0), // 小型機: 薄い灰色
+                }
+            };
+            let position = enemy.previous_position.lerp(enemy.position, alpha);
+            draw_enemy_shape(renderer, enemy, position, base_color);
+            
+            // 重ねたロックオン数（2以上の場合のみ右上に表示）
+            if enemy.lock_stack > 1 {
+                renderer.draw_text(&format!("x{}", enemy.lock_stack), position.x + 10.0, position.y - 10.0, 16.0, YELLOW);
+            }
+        }
+        
+        // 敵弾の描画 - 白い芯のピンクの小円
+        for bullet in &self.enemy_bullets {
+            let position = bullet.previous_position.lerp(bullet.position, alpha);
+            let radius = bullet.hitbox.bounding_radius();
+            renderer.draw_circle(position.x, position.y, radius, Color::new(1.0, 0.4, 0.7, 1.0));
+            renderer.draw_circle(position.x, position.y, radius / 2.0, WHITE);
+        }
+        
+        // 通常レーザーの描画 - 進行方向に沿ったシアンの線（既定は幅3px、チャージショットは当たり判定の幅）
+        for laser in &self.normal_lasers {
+            let laser_length = 15.0; // レーザーの長さ
+            let position = laser.previous_position.lerp(laser.position, alpha);
+            let half = laser.velocity.normalize_or(Vec2::NEG_Y) * laser_length / 2.0;
+            renderer.draw_line(
+                position.x + half.x, 
+                position.y + half.y,
+                position.x - half.x, 
+                position.y - half.y,
+                laser.hitbox.bounding_radius() * 2.0, 
+                Color::new(0.0, 1.0, 1.0, 1.0) // CYAN
+            );
+            
+            // 貫通できるレーザーは白い芯を重ねる
+            if laser.pierce > 0 {
+                renderer.draw_line(position.x + half.x, position.y + half.y, position.x - half.x, position.y - half.y, 2.0, WHITE);
+            }
+        }
+        
+        // ホーミングレーザーの描画 - 先端から尾へ細くなる黄色の帯（先端位置の履歴）
+        for laser in &self.lock_on_lasers {
+            // 発射待ちのレーザーは描画しない
+            if !laser.is_launched(self.time) {
+                continue;
+            }
+            
+            // 最新の履歴点は補間した先端位置に置き換える
+            let mut previous = laser.previous_position.lerp(laser.position, alpha);
+            let mut previous_width = HOMING_RIBBON_WIDTH;
+            for (age, point) in laser.trail.iter().enumerate().skip(1) {
+                let width = HOMING_RIBBON_WIDTH * (1.0 - age as f32 / TRAIL_LENGTH as f32);
+                draw_ribbon_segment(renderer, previous, point, previous_width, width, YELLOW);
+                previous = point;
+                previous_width = width;
+            }
+        }
+        
+        // 命中しなかったホーミングレーザーの消滅エフェクト - 放射状に広がりながら薄れる火花
+        for fizzle in &self.laser_fizzles {
+            let progress = fizzle.progress();
+            let inner = 2.0 + FIZZLE_SPREAD * progress;
+            let outer = inner + 4.0 * (1.0 - progress) + 2.0;
+            let color = Color::new(1.0, 1.0, 0.0, 1.0 - progress);
+            for i in 0..FIZZLE_SPARKS {
+                let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / FIZZLE_SPARKS as f32);
+                let start = fizzle.position + direction * inner;
+                let end = fizzle.position + direction * outer;
+                renderer.draw_line(start.x, start.y, end.x, end.y, 1.5, color);
+            }
+        }
+        
+        // ワイヤーフレーム描画（ロックオンシステム）
+        if self.lock_system.active {
+            self.draw_wireframe(renderer, alpha);
+            if self.lock_system.mode == LockMode::Area {
+                self.draw_lock_progress(renderer, alpha);
+            }
+        }
+        
+        // ボムのエフェクト - 画面全体の白い閃光と、発動位置から画面外まで広がる衝撃波
+        if let Some(blast) = &self.bomb_blast {
+            let progress = blast.progress();
+            let fade = 1.0 - progress;
+            renderer.draw_rectangle(0.0, 0.0, 800.0, 600.0, Color::new(1.0, 1.0, 1.0, 0.6 * fade * fade));
+            let radius = BOMB_WAVE_RADIUS * progress;
+            let wave_color = Color::new(1.0, 0.8, 0.3, fade);
+            for i in 0..BOMB_WAVE_SEGMENTS {
+                let start = blast.center + Vec2::from_angle(i as f32 * std::f32::consts::TAU / BOMB_WAVE_SEGMENTS as f32) * radius;
+                let end = blast.center + Vec2::from_angle((i + 1) as f32 * std::f32::consts::TAU / BOMB_WAVE_SEGMENTS as f32) * radius;
+                renderer.draw_line(start.x, start.y, end.x, end.y, 12.0 * fade + 2.0, wave_color);
+            }
+        }
+        
+        // HUD（スコア・ロックオン数・ボーナス表示・操作説明）
+        self.draw_hud(renderer, alpha);
+    }
+    
+    fn draw_wireframe(&self, renderer: &mut dyn Renderer, alpha: f32) {
+        // ワイヤーフレームの描画（形状別の点線、ロックオン数に応じた色変化）
+        // 前方三角形は描画補間した自機位置に頂点を合わせる
+        let center = match self.lock_system.shape {
+            LockShape::Cone { .. } => self.player.previous_position.lerp(self.player.position, alpha),
+            _ => self.lock_system.center,
+        };
+        
+        // ロックオン数に応じた色変化
+        let wireframe_color = match self.lock_system.locked_enemies.len() {
+            0 => WHITE,           // ロックオンなし: 白
+            1..=2 => GREEN,       // 1-2機: 緑
+            3..=4 => YELLOW,      // 3-4機: 黄
+            5..=6 => ORANGE,      // 5-6機: オレンジ
+            _ => RED,             // 7機以上: 赤
+        };
+        
+        match self.lock_system.shape {
+            LockShape::Circle | LockShape::GrowingCircle { .. } => {
+                let segments = 32; // 円を32個の線分で描画
+                let radius = self.lock_system.current_radius();
+                
+                for i in 0..segments {
+                    // 点線効果のため、偶数番目の線分のみ描画
+                    if i % 2 == 0 {
+                        let angle1 = (i as f32) * 2.0 * std::f32::consts::PI / (segments as f32);
+                        let angle2 = ((i + 1) as f32) * 2.0 * std::f32::consts::PI / (segments as f32);
+                        
+                        let x1 = center.x + radius * angle1.cos();
+                        let y1 = center.y + radius * angle1.sin();
+                        let x2 = center.x + radius * angle2.cos();
+                        let y2 = center.y + radius * angle2.sin();
+                        
+                        renderer.draw_line(x1, y1, x2, y2, 2.0, wireframe_color);
+                    }
+                }
+            }
+            LockShape::Cone { length, half_angle } => {
+                let [apex, left, right] = LockOnSystem::cone_vertices(center, length, half_angle);
+                draw_dashed_line(renderer, apex, left, 16, wireframe_color);
+                draw_dashed_line(renderer, left, right, 12, wireframe_color);
+                draw_dashed_line(renderer, right, apex, 16, wireframe_color);
+            }
+            LockShape::Band { half_height } => {
+                let screen_width = 800.0;
+                for y in [center.y - half_height, center.y + half_height] {
+                    draw_dashed_line(renderer, Vec2::new(0.0, y), Vec2::new(screen_width, y), 40, wireframe_color);
+                }
+            }
+        }
+        
+        // 中心点の描画
+        renderer.draw_circle(center.x, center.y, 3.0, wireframe_color);
+    }
+    
+    fn draw_lock_progress(&self, renderer: &mut dyn Renderer, alpha: f32) {
+        // ロックオン進捗リング（敵機の周囲、真上から時計回りに進捗分の円弧）
+        // ロックオン済みで重ねられる敵機は、次のロックオンまでの進捗
+        let segments = 24; // 1周を24個の線分で描画
+        let radius = 15.0;
+        
+        for enemy in &self.enemies {
+            if enemy.lock_stack >= self.lock_system.stack_limit(enemy) || enemy.lock_timer <= 0.0 {
+                continue;
+            }
+            
+            let progress = self.lock_system.progress(enemy);
+            let position = enemy.previous_position.lerp(enemy.position, alpha);
+            let drawn_segments = (progress * segments as f32).ceil() as usize;
+            for i in 0..drawn_segments {
+                let t1 = (i as f32) / (segments as f32);
+                let t2 = ((i + 1) as f32 / (segments as f32)).min(progress);
+                let angle1 = t1 * 2.0 * std::f32::consts::PI - std::f32::consts::FRAC_PI_2;
+                let angle2 = t2 * 2.0 * std::f32::consts::PI - std::f32::consts::FRAC_PI_2;
+                
+                let x1 = position.x + radius * angle1.cos();
+                let y1 = position.y + radius * angle1.sin();
+                let x2 = position.x + radius * angle2.cos();
+                let y2 = position.y + radius * angle2.sin();
+                
+                renderer.draw_line(x1, y1, x2, y2, 2.0, YELLOW);
+            }
+        }
+    }
+}
+
+// タイプ別の敵機の形状（当たり判定と同じ大きさ）
+fn draw_enemy_shape(renderer: &mut dyn Renderer, enemy: &Enemy, position: Vec2, color: Color) {
+    let dark = Color::new(0.0, 0.0, 0.0, 0.6);
+    match (enemy.enemy_type, enemy.hitbox) {
+        // 射撃型・母艦は矩形（母艦は中央下部に発進口）
+        (EnemyType::Carrier, Hitbox::Rect { half_size }) => {
+            renderer.draw_rectangle(position.x - half_size.x, position.y - half_size.y, half_size.x * 2.0, half_size.y * 2.0, color);
+            renderer.draw_rectangle(position.x - 8.0, position.y, 16.0, half_size.y, dark);
+        }
+        (_, Hitbox::Rect { half_size }) => {
+            renderer.draw_rectangle(position.x - half_size.x, position.y - half_size.y, half_size.x * 2.0, half_size.y * 2.0, color);
+            renderer.draw_circle(position.x, position.y, half_size.x / 2.0, dark);
+        }
+        // 分裂型は割れ目の入った円
+        (EnemyType::Splitter, Hitbox::Circle { radius }) => {
+            renderer.draw_circle(position.x, position.y, radius, color);
+            for i in 0..3 {
+                let end = position + Vec2::from_angle(i as f32 * std::f32::consts::TAU / 3.0 + 0.5) * radius;
+                renderer.draw_line(position.x, position.y, end.x, end.y, 2.0, dark);
+            }
+        }
+        // シールド型は本体とシールドの外周（耐久力が減ると薄くなる）
+        (EnemyType::Shielded, Hitbox::Circle { radius }) => {
+            renderer.draw_circle(position.x, position.y, radius - 5.0, color);
+            let shield_alpha = enemy.hp as f32 / EnemyType::Shielded.hit_points() as f32;
+            let shield_color = Color::new(0.5, 0.9, 1.0, shield_alpha);
+            for i in 0..SHIELD_SEGMENTS {
+                let start = position + Vec2::from_angle(i as f32 * std::f32::consts::TAU / SHIELD_SEGMENTS as f32) * radius;
+                let end = position + Vec2::from_angle((i + 1) as f32 * std::f32::consts::TAU / SHIELD_SEGMENTS as f32) * radius;
+                renderer.draw_line(start.x, start.y, end.x, end.y, 2.0, shield_color);
+            }
+        }
+        // 自爆型は進行方向を向いた三角形
+        (EnemyType::Kamikaze, Hitbox::Circle { radius }) => {
+            let forward = enemy.velocity.normalize_or(Vec2::Y);
+            let side = forward.perp() * radius * 0.8;
+            renderer.draw_triangle(position + forward * radius, position - forward * radius + side, position - forward * radius - side, color);
+        }
+        (_, Hitbox::Circle { radius }) => {
+            renderer.draw_circle(position.x, position.y, radius, color);
+        }
+    }
+}
+
+// 帯の1区間（start から end へ幅を線形に変える台形を2つの三角形で描画）
+fn draw_ribbon_segment(renderer: &mut dyn Renderer, start: Vec2, end: Vec2, start_width: f32, end_width: f32, color: Color) {
+    let normal = (end - start).perp().normalize_or_zero();
+    if normal == Vec2::ZERO {
+        return;
+    }
+    let start_side = normal * start_width / 2.0;
+    let end_side = normal * end_width / 2.0;
+    renderer.draw_triangle(start + start_side, start - start_side, end + end_side, color);
+    renderer.draw_triangle(start - start_side, end - end_side, end + end_side, color);
+}
+
+// 点線（start から end までを segments 等分し、偶数番目の線分のみ描画）
+fn draw_dashed_line(renderer: &mut dyn Renderer, start: Vec2, end: Vec2, segments: usize, color: Color) {
+    for i in (0..segments).step_by(2) {
+        let p1 = start.lerp(end, i as f32 / segments as f32);
+        let p2 = start.lerp(end, (i + 1) as f32 / segments as f32);
+        renderer.draw_line(p1.x, p1.y, p2.x, p2.y, 2.0, color);
+    }
+}
@@ -0,0 +1,141 @@
+// スコア（撃破点・同時撃破ボーナスとボーナス表示）
+
+use std::fmt::Write;
+
+use macroquad::prelude::*;
+
+use super::Game;
+
+// 撃破点
+pub const NORMAL_LASER_SCORE: u32 = 100;   // 通常レーザー
+pub const LOCK_ON_LASER_SCORE: u32 = 200;  // ロックオンレーザー（1機あたり）
+pub const BOMB_SCORE: u32 = 50;            // ボム（1機あたり、同時撃破ボーナスなし）
+
+// 一斉発射の同時撃破ボーナス（レーザー2本以上、n 本で 50 × n × (n + 1)）
+// 2〜6本: 300, 600, 1000, 1500, 2100（重ねたロックオンで7本以上も同じ式で増加）
+pub fn lock_on_bonus(laser_count: usize) -> u32 {
+    if laser_count < 2 {
+        return 0;
+    }
+    let laser_count = laser_count.min(u8::MAX as usize) as u32;
+    50 * laser_count * (laser_count + 1)
+}
+
+// 飛行中の一斉発射（全レーザーの完了時に同時撃破ボーナスを加算）
+#[derive(Clone, Debug)]
+pub struct Volley {
+    pub id: u32,
+    pub remaining: usize,  // 飛行中のレーザー数
+    pub hits: usize,       // 敵機に命中したレーザー数
+}
+
+// ボーナススコア表示（画面中央から上昇しながらフェードアウト）
+#[derive(Debug, Clone)]
+pub struct BonusDisplay {
+    pub text: String,
+    pub position: Vec2,
+    pub previous_position: Vec2, // 前回ステップの位置（描画補間用）
+    pub timer: f32,
+    pub max_time: f32,
+}
+
+impl Default for BonusDisplay {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            position: Vec2::ZERO,
+            previous_position: Vec2::ZERO,
+            timer: 0.0,
+            max_time: 2.0, // 2秒間表示
+        }
+    }
+}
+
+impl BonusDisplay {
+    // プールから再利用する際の初期化（文字列バッファは使い回す）
+    pub fn reset(&mut self, bonus_score: u32, position: Vec2) {
+        self.text.clear();
+        let _ = write!(self.text, "BONUS +{}", bonus_score);
+        self.position = position;
+        self.previous_position = position;
+        self.timer = 0.0;
+    }
+    
+    pub fn update(&mut self, delta_time: f32) {
+        self.timer += delta_time;
+        // フェードアウト効果のために上に移動
+        self.position.y -= 30.0 * delta_time;
+    }
+    
+    pub fn is_expired(&self) -> bool {
+        self.timer >= self.max_time
+    }
+    
+    pub fn get_alpha(&self) -> f32 {
+        // フェードアウト効果
+        (1.0 - (self.timer / self.max_time)).max(0.0)
+    }
+}
+
+impl Game {
+    // ロックオンレーザー命中スコア（200点 × 命中数）
+    pub(super) fn award_lock_on_hits(&mut self, hit_count: usize) {
+        let base_score = hit_count as u32 * LOCK_ON_LASER_SCORE;
+        self.score += base_score;
+        
+        game_log!("Lock-on laser hits: {} lasers, +{} points", hit_count, base_score);
+    }
+    
+    // 全レーザーが完了した一斉発射の同時撃破ボーナス（命中したレーザー数で計算、発射順に判定）
+    pub(super) fn award_completed_volleys(&mut self) {
+        let mut volley_idx = 0;
+        while volley_idx < self.volleys.len() {
+            if self.volleys[volley_idx].remaining > 0 {
+                volley_idx += 1;
+                continue;
+            }
+            
+            let volley = self.volleys.remove(volley_idx);
+            
+            // 同時ロックオン撃破ボーナス計算（レーザー数に応じて増加）
+            let bonus_score = lock_on_bonus(volley.hits);
+            if bonus_score > 0 {
+                self.score += bonus_score;
+                self.stats.bonus_points += bonus_score;
+                
+                // ボーナススコア表示を追加
+                let display_pos = Vec2::new(400.0, 300.0); // 画面中央
+                self.bonus_displays.spawn_with(|display| display.reset(bonus_score, display_pos));
+                
+                game_log!("Volley with {} hits completed, +{} points (bonus)", volley.hits, bonus_score);
+            }
+        }
+    }
+    
+    pub(super) fn update_bonus_displays(&mut self, delta_time: f32) {
+        for bonus_display in &mut self.bonus_displays {
+            bonus_display.update(delta_time);
+        }
+        
+        // 期限切れのボーナス表示を削除
+        self.bonus_displays.retain(|display| !display.is_expired());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn lock_on_bonus_scales_with_the_number_of_lasers_in_a_volley() {
+        assert_eq!(lock_on_bonus(0), 0);
+        assert_eq!(lock_on_bonus(1), 0);
+        assert_eq!(lock_on_bonus(2), 300);
+        assert_eq!(lock_on_bonus(3), 600);
+        assert_eq!(lock_on_bonus(4), 1000);
+        assert_eq!(lock_on_bonus(5), 1500);
+        assert_eq!(lock_on_bonus(6), 2100);
+        assert_eq!(lock_on_bonus(7), 2800);
+        assert_eq!(lock_on_bonus(12), 7800);
+    }
+}
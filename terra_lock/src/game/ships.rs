@@ -0,0 +1,156 @@
+// 自機の種類（移動性能・当たり判定・通常ショット・ロックオン性能の定義）と自機別ハイスコア
+
+use macroquad::prelude::*;
+
+use super::{LockShape, PLAYER_HITBOX};
+use crate::collision::Hitbox;
+
+// 通常ショットの発射パターン
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ShotPattern {
+    Single,   // 真上に1発
+    Spread,   // 扇状に3発
+    Wide,     // 左右に並べて平行に2発
+}
+
+impl ShotPattern {
+    pub fn name(self) -> &'static str {
+        match self {
+            ShotPattern::Single => "single",
+            ShotPattern::Spread => "spread",
+            ShotPattern::Wide => "wide",
+        }
+    }
+}
+
+// 自機の定義
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ShipDefinition {
+    pub name: &'static str,
+    pub color: Color,
+    pub speed: f32,                 // マウス位置への追従速度（px/秒、無限大で即時）
+    pub hitbox: Hitbox,
+    pub shot_pattern: ShotPattern,
+    pub normal_laser_speed: f32,
+    pub lock_shape: LockShape,
+    pub lock_radius: f32,           // 円形のロックオン範囲の半径（拡大する円では最大半径）
+    pub max_targets: u8,
+    pub homing_initial_speed: f32,
+    pub homing_acceleration: f32,
+    pub homing_max_speed: f32,
+}
+
+impl ShipDefinition {
+    // ロックオン範囲の表示名
+    pub fn lock_shape_name(&self) -> &'static str {
+        match self.lock_shape {
+            LockShape::Circle => "circle",
+            LockShape::Cone { .. } => "cone",
+            LockShape::Band { .. } => "band",
+            LockShape::GrowingCircle { .. } => "growing",
+        }
+    }
+}
+
+pub const SHIP_COUNT: usize = 3;
+
+// 選択可能な自機（先頭が既定、既定の GameConfig と同じ性能）
+pub const SHIPS: [ShipDefinition; SHIP_COUNT] = [
+    ShipDefinition {
+        name: "TERRA",
+        color: BLUE,
+        speed: f32::INFINITY,
+        hitbox: PLAYER_HITBOX,
+        shot_pattern: ShotPattern::Single,
+        normal_laser_speed: 350.0,
+        lock_shape: LockShape::Circle,
+        lock_radius: 100.0,
+        max_targets: 6,
+        homing_initial_speed: 140.0,
+        homing_acceleration: 560.0,
+        homing_max_speed: 560.0,
+    },
+    ShipDefinition {
+        name: "VULCAN",
+        color: Color::new(0.2, 0.8, 0.9, 1.0),
+        speed: 900.0,
+        hitbox: Hitbox::Rect { half_size: Vec2::new(12.0, 9.0) },
+        shot_pattern: ShotPattern::Spread,
+        normal_laser_speed: 420.0,
+        lock_shape: LockShape::Cone { length: 320.0, half_angle: 0.35 },
+        lock_radius: 100.0,
+        max_targets: 4,
+        homing_initial_speed: 200.0,
+        homing_acceleration: 700.0,
+        homing_max_speed: 700.0,
+    },
+    ShipDefinition {
+        name: "SPECTER",
+        color: Color::new(0.7, 0.5, 1.0, 1.0),
+        speed: 600.0,
+        hitbox: Hitbox::Rect { half_size: Vec2::new(8.0, 6.0) },
+        shot_pattern: ShotPattern::Wide,
+        normal_laser_speed: 320.0,
+        lock_shape: LockShape::GrowingCircle { start_radius: 30.0, growth_rate: 120.0 },
+        lock_radius: 140.0,
+        max_targets: 8,
+        homing_initial_speed: 110.0,
+        homing_acceleration: 450.0,
+        homing_max_speed: 480.0,
+    },
+];
+
+// ハイスコア表（降順、上位のみ保持）
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HighScoreTable {
+    scores: Vec<u32>,
+}
+
+impl HighScoreTable {
+    pub const CAPACITY: usize = 5;
+
+    pub fn new() -> Self {
+        Self {
+            scores: Vec::with_capacity(Self::CAPACITY + 1),
+        }
+    }
+
+    // スコアを登録し、表に入った順位（0始まり）を返す
+    pub fn record(&mut self, score: u32) -> Option<usize> {
+        let rank = self.scores.iter().position(|&entry| score > entry).unwrap_or(self.scores.len());
+        if rank >= Self::CAPACITY {
+            return None;
+        }
+        self.scores.insert(rank, score);
+        self.scores.truncate(Self::CAPACITY);
+        Some(rank)
+    }
+
+    pub fn scores(&self) -> &[u32] {
+        &self.scores
+    }
+
+    pub fn best(&self) -> Option<u32> {
+        self.scores.first().copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn high_score_table_keeps_the_best_scores_in_order() {
+        let mut table = HighScoreTable::new();
+        for score in [300, 100, 500, 200, 400, 0] {
+            table.record(score);
+        }
+        assert_eq!(table.scores(), &[500, 400, 300, 200, 100]);
+
+        // 同点は後から登録したものが下位
+        assert_eq!(table.record(300), Some(3));
+        assert_eq!(table.scores(), &[500, 400, 300, 300, 200]);
+        assert_eq!(table.record(50), None);
+        assert_eq!(table.best(), Some(500));
+    }
+}
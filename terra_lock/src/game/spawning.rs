@@ -0,0 +1,156 @@
+// 敵機出現（難易度カーブに応じた出現間隔・出現数・速度）
+
+use macroquad::prelude::*;
+
+use super::{Enemy, EnemyType, Game};
+
+// 出現する敵機タイプ（タイプ、出現し始める難易度段階、出現の重み）
+// 小型機は分裂・母艦の発進でのみ出現
+const SPAWN_TABLE: [(EnemyType, i32, u32); 9] = [
+    (EnemyType::Straight, 0, 4),
+    (EnemyType::Zigzag, 0, 4),
+    (EnemyType::Arc, 0, 4),
+    (EnemyType::Homing, 0, 4),
+    (EnemyType::Splitter, 0, 2),
+    (EnemyType::Shielded, 0, 2),
+    (EnemyType::Kamikaze, 1, 2),
+    (EnemyType::Shooter, 1, 2),
+    (EnemyType::Carrier, 2, 1),
+];
+
+impl Game {
+    // 出現タイマーを進め、出現間隔に達したら敵機を出現させる
+    pub(super) fn update_spawning(&mut self, delta_time: f32) {
+        self.enemy_spawn_timer += delta_time;
+        let (spawn_interval, max_spawn_count, speed_multiplier) = self.calculate_difficulty_parameters();
+        
+        if self.enemy_spawning && self.enemy_spawn_timer >= spawn_interval {
+            // 難易度に応じた出現数の決定
+            let spawn_count = if max_spawn_count >= 5 && self.rng.gen_range(0.0, 1.0) < 0.05 {
+                5 // 高難易度時：5%の確率で5機
+            } else if max_spawn_count >= 4 && self.rng.gen_range(0.0, 1.0) < 0.1 {
+                4 // 中難易度時：10%の確率で4機
+            } else if max_spawn_count >= 3 && self.rng.gen_range(0.0, 1.0) < 0.15 {
+                3 // 15%の確率で3機
+            } else if self.rng.gen_range(0.0, 1.0) < 0.3 {
+                2 // 30%の確率で2機
+            } else {
+                1 // 40%の確率で1機
+            };
+            
+            for _ in 0..spawn_count {
+                self.spawn_enemy_with_difficulty(speed_multiplier);
+            }
+            self.enemy_spawn_timer = 0.0;
+            self.stats.record_enemy_count(self.enemies.len());
+        }
+    }
+    
+    // 難易度段階（既定30秒ごとに上昇）
+    pub(super) fn difficulty_level(&self) -> i32 {
+        let current_time = self.time;
+        let elapsed_time = current_time - self.game_start_time;
+        (elapsed_time / self.config.difficulty_step_time).floor() as i32
+    }
+    
+    pub(super) fn calculate_difficulty_parameters(&self) -> (f32, i32, f32) {
+        Self::difficulty_parameters_for_level(self.difficulty_level())
+    }
+    
+    // 難易度段階ごとのパラメータ（出現間隔、最大同時出現数、速度倍率）
+    pub fn difficulty_parameters_for_level(difficulty_level: i32) -> (f32, i32, f32) {
+        // 敵機出現間隔（1.5秒 → 0.8秒まで段階的に短縮）
+        let spawn_interval = (1.5 - (difficulty_level as f32 * 0.1)).max(0.8);
+        
+        // 最大同時出現数（3機 → 5機まで段階的に増加）
+        let max_spawn_count = (3 + difficulty_level).min(5);
+        
+        // 敵機速度倍率（1.0 → 1.5まで段階的に上昇）
+        let speed_multiplier = 1.0 + (difficulty_level as f32 * 0.1).min(0.5);
+        
+        (spawn_interval, max_spawn_count, speed_multiplier)
+    }
+    
+    fn spawn_enemy_with_difficulty(&mut self, speed_multiplier: f32) {
+        let screen_width = 800.0;
+        let enemy_radius = 10.0;
+        
+        // 画面上部のランダムな位置に敵機を生成
+        // 既存の敵機がある場合は、近い位置に配置する確率を上げる
+        let x = if !self.enemies.is_empty() && self.rng.gen_range(0.0, 1.0) < 0.4 {
+            // 40%の確率で既存の敵機の近くに配置
+            let existing_enemy = &self.enemies[self.rng.gen_range(0, self.enemies.len())];
+            let offset = self.rng.gen_range(-100.0, 100.0);
+            (existing_enemy.position.x + offset).clamp(enemy_radius, screen_width - enemy_radius)
+        } else {
+            // 通常のランダム配置
+            self.rng.gen_range(enemy_radius, screen_width - enemy_radius)
+        };
+        
+        let y = -enemy_radius; // 画面上部の少し外側から出現
+        
+        // 敵機タイプを難易度段階に応じた重みでランダムに選択
+        let enemy_type = Self::pick_enemy_type(self.difficulty_level(), self.rng.gen_range(0.0, 1.0));
+        
+        let base_speed = 120.0 * speed_multiplier; // 難易度に応じた速度調整
+        self.spawn_enemy(enemy_type, Vec2::new(x, y), base_speed);
+    }
+    
+    // 難易度段階で出現するタイプの中から重みに従って選択（roll は0.0〜1.0の乱数）
+    pub fn pick_enemy_type(difficulty_level: i32, roll: f32) -> EnemyType {
+        let available = SPAWN_TABLE.iter().filter(|&&(_, level, _)| level <= difficulty_level);
+        let total_weight: u32 = available.clone().map(|&(_, _, weight)| weight).sum();
+        let mut remaining = roll * total_weight as f32;
+        for &(enemy_type, _, weight) in available {
+            if remaining < weight as f32 {
+                return enemy_type;
+            }
+            remaining -= weight as f32;
+        }
+        EnemyType::Straight
+    }
+    
+    // 指定位置に敵機を追加（容量不足の場合はNone）
+    pub fn spawn_enemy(&mut self, enemy_type: EnemyType, position: Vec2, base_speed: f32) -> Option<usize> {
+        self.enemies.spawn(Enemy {
+            position,
+            previous_position: position,
+            hitbox: enemy_type.hitbox(),
+            velocity: Vec2::new(0.0, base_speed), // 初期速度（後で動作パターンで変更）
+            is_locked: false,
+            lock_stack: 0,
+            lock_timer: 0.0,
+            hp: enemy_type.hit_points(),
+            enemy_type,
+            spawn_time: self.time,
+            base_speed,
+            hover_time: 0.0,
+            action_timer: 0.0,
+            charging: false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn reachable_types(difficulty_level: i32) -> Vec<EnemyType> {
+        let mut types: Vec<EnemyType> = (0..100)
+            .map(|i| Game::pick_enemy_type(difficulty_level, i as f32 / 100.0))
+            .collect();
+        types.dedup();
+        types
+    }
+    
+    #[test]
+    fn new_enemy_types_appear_as_difficulty_rises() {
+        let level_0 = reachable_types(0);
+        assert!(level_0.contains(&EnemyType::Splitter) && level_0.contains(&EnemyType::Shielded));
+        assert!(!level_0.contains(&EnemyType::Shooter) && !level_0.contains(&EnemyType::Carrier));
+        
+        let level_2 = reachable_types(2);
+        assert_eq!(level_2.len(), SPAWN_TABLE.len());
+        assert!(!level_2.contains(&EnemyType::Drone));
+    }
+}
@@ -0,0 +1,19 @@
+// ゲームモジュールの単体テスト用の共通フィクスチャ
+
+use macroquad::prelude::*;
+
+use super::{EnemyType, Game, GameConfig, LockOnLaser};
+
+pub(super) fn homing_laser(target_enemy_id: Option<usize>) -> LockOnLaser {
+    LockOnLaser::new(Vec2::new(400.0, 500.0), Vec2::new(400.0, 300.0), target_enemy_id, &GameConfig::default(), 0.0)
+}
+
+// 静止した敵機を count 機配置したゲーム（x座標が生成順の添字）
+pub(super) fn game_with_enemies(count: usize) -> Game {
+    let mut game = Game::new();
+    game.enemies.clear();
+    for i in 0..count {
+        game.spawn_enemy(EnemyType::Straight, Vec2::new(i as f32, 0.0), 0.0);
+    }
+    game
+}
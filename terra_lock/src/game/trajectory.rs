@@ -0,0 +1,176 @@
+// ホーミングレーザーの軌道（3次ベジェ曲線・旋回速度制限付きの誘導）と軌跡の履歴
+
+use macroquad::prelude::*;
+
+// 軌道の方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TrajectoryMode {
+    Curve,     // 発射時に決めた3次ベジェ曲線（終点は目標の移動に追従）
+    Steering,  // 旋回速度の上限内で目標へ向きを変えながら直進
+}
+
+impl TrajectoryMode {
+    // 設定値のID（ホストページの setConfig 用）
+    pub fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(TrajectoryMode::Curve),
+            1 => Some(TrajectoryMode::Steering),
+            _ => None,
+        }
+    }
+}
+
+// 誘導方式の寿命（秒、命中しない場合はこの時間で消滅）
+pub const STEERING_LIFETIME: f32 = 3.0;
+
+// 誘導方式の旋回速度の1秒あたりの増加率（目標の周囲を回り続けないよう時間とともに鋭く曲がる）
+const STEERING_TURN_GROWTH: f32 = 2.0;
+
+// 3次ベジェ曲線の制御点（開始点・目標点からの相対位置、目標が動いても形を保つ）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Trajectory {
+    pub launch_offset: Vec2,   // 第1制御点の開始点からの位置（発射方向）
+    pub arrival_offset: Vec2,  // 第2制御点の目標点からの位置（進入方向）
+}
+
+impl Trajectory {
+    // 中間点の上方 height px を頂点とする放物線と同じ曲線（2次ベジェ曲線の次数上げ）
+    pub fn arc(start: Vec2, target: Vec2, height: f32) -> Self {
+        let control_point = (start + target) / 2.0 - Vec2::new(0.0, height);
+        Self {
+            launch_offset: (control_point - start) * (2.0 / 3.0),
+            arrival_offset: (control_point - target) * (2.0 / 3.0),
+        }
+    }
+
+    // 軌道上の点（t = 0.0 で開始点、1.0 で目標点）
+    pub fn point(&self, start: Vec2, target: Vec2, t: f32) -> Vec2 {
+        cubic_bezier_point(start, start + self.launch_offset, target + self.arrival_offset, target, t)
+    }
+
+    // 曲線の長さの概算（弦と制御点の折れ線の長さの平均）
+    pub fn approximate_length(&self, start: Vec2, target: Vec2) -> f32 {
+        let control1 = start + self.launch_offset;
+        let control2 = target + self.arrival_offset;
+        let polygon = start.distance(control1) + control1.distance(control2) + control2.distance(target);
+        (start.distance(target) + polygon) / 2.0
+    }
+}
+
+// 3次ベジェ曲線: B(t) = (1-t)³P₀ + 3(1-t)²tP₁ + 3(1-t)t²P₂ + t³P₃
+pub fn cubic_bezier_point(p0: Vec2, p1: Vec2, p2: Vec2, p3: Vec2, t: f32) -> Vec2 {
+    let t_inv = 1.0 - t;
+    p0 * (t_inv * t_inv * t_inv) + p1 * (3.0 * t_inv * t_inv * t) + p2 * (3.0 * t_inv * t * t) + p3 * (t * t * t)
+}
+
+// 向き heading を目標方向 desired へ最大 max_turn ラジアンまで回転
+pub fn turn_towards(heading: Vec2, desired: Vec2, max_turn: f32) -> Vec2 {
+    let angle = heading.perp_dot(desired).atan2(heading.dot(desired));
+    Vec2::from_angle(angle.clamp(-max_turn, max_turn)).rotate(heading)
+}
+
+// 誘導方式の旋回速度（発射からの経過時間に応じて増加）
+pub fn steering_turn_rate(turn_rate: f32, elapsed_time: f32) -> f32 {
+    turn_rate * (1.0 + STEERING_TURN_GROWTH * elapsed_time)
+}
+
+// 軌跡の履歴の点数（1ステップ1点、先端から尾へ細くなる帯として描画）
+pub const TRAIL_LENGTH: usize = 24;
+
+// レーザー先端の位置の履歴（固定長のリングバッファ）
+#[derive(Clone, Debug)]
+pub struct Trail {
+    points: [Vec2; TRAIL_LENGTH],
+    len: usize,
+    newest: usize,
+}
+
+impl Trail {
+    pub fn new(point: Vec2) -> Self {
+        Self {
+            points: [point; TRAIL_LENGTH],
+            len: 1,
+            newest: 0,
+        }
+    }
+
+    pub fn push(&mut self, point: Vec2) {
+        self.newest = (self.newest + 1) % TRAIL_LENGTH;
+        self.points[self.newest] = point;
+        self.len = (self.len + 1).min(TRAIL_LENGTH);
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // 新しい点から順に列挙
+    pub fn iter(&self) -> impl Iterator<Item = Vec2> + '_ {
+        (0..self.len).map(move |age| self.points[(self.newest + TRAIL_LENGTH - age) % TRAIL_LENGTH])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn curve_starts_and_ends_at_endpoints() {
+        let start = Vec2::new(100.0, 500.0);
+        let target = Vec2::new(300.0, 200.0);
+        let trajectory = Trajectory { launch_offset: Vec2::new(-80.0, -40.0), arrival_offset: Vec2::new(30.0, -60.0) };
+
+        assert_eq!(trajectory.point(start, target, 0.0), start);
+        assert_eq!(trajectory.point(start, target, 1.0), target);
+    }
+
+    #[test]
+    fn arc_bends_above_the_midpoint() {
+        // 頂点は中間点の100px上、t=0.5では頂点方向へ50px
+        let start = Vec2::new(0.0, 0.0);
+        let target = Vec2::new(100.0, 0.0);
+        let point = Trajectory::arc(start, target, 100.0).point(start, target, 0.5);
+        assert!((point - Vec2::new(50.0, -50.0)).length() < 1e-4);
+    }
+
+    #[test]
+    fn approximate_length_is_between_chord_and_control_polygon() {
+        let start = Vec2::new(0.0, 0.0);
+        let target = Vec2::new(100.0, 0.0);
+        assert!((Trajectory::arc(start, target, 0.0).approximate_length(start, target) - 100.0).abs() < 1e-4);
+
+        let length = Trajectory::arc(start, target, 100.0).approximate_length(start, target);
+        assert!(length > 100.0 && length < 100.0 + 2.0 * 200.0 / 3.0);
+    }
+
+    #[test]
+    fn turn_towards_is_limited_by_max_turn() {
+        let heading = Vec2::new(0.0, -1.0);
+
+        // 目標が右（時計回りに90度）、最大0.5ラジアンまで
+        let turned = turn_towards(heading, Vec2::new(1.0, 0.0), 0.5);
+        assert!((turned.angle_between(heading).abs() - 0.5).abs() < 1e-5);
+        assert!(turned.x > 0.0);
+
+        // 上限内なら目標方向へ一致
+        let turned = turn_towards(heading, Vec2::new(0.1, -1.0).normalize(), 0.5);
+        assert!((turned - Vec2::new(0.1, -1.0).normalize()).length() < 1e-5);
+    }
+
+    #[test]
+    fn trail_keeps_the_newest_points_first() {
+        let mut trail = Trail::new(Vec2::ZERO);
+        for i in 1..=30 {
+            trail.push(Vec2::new(i as f32, 0.0));
+        }
+
+        assert_eq!(trail.len(), TRAIL_LENGTH);
+        let xs: Vec<f32> = trail.iter().map(|point| point.x).collect();
+        assert_eq!(xs[0], 30.0);
+        assert_eq!(xs[TRAIL_LENGTH - 1], 30.0 - (TRAIL_LENGTH - 1) as f32);
+    }
+}
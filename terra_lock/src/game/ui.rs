@@ -0,0 +1,486 @@
+// 画面UI（タイトル・自機選択・デモ・ゲームオーバー画面、プレイ中のHUD、デバッグ表示）
+
+use macroquad::prelude::*;
+
+use crate::renderer::Renderer;
+use crate::stats::VOLLEY_SIZE_BUCKETS;
+
+use super::{EnemyType, FireMode, Game, HighScoreTable, LockOnSystem, NormalWeapon, SHIPS};
+
+// 自機選択画面のパネル配置（横に並べる）
+const SHIP_PANEL_X: f32 = 20.0;
+const SHIP_PANEL_Y: f32 = 130.0;
+const SHIP_PANEL_WIDTH: f32 = 240.0;
+const SHIP_PANEL_HEIGHT: f32 = 340.0;
+const SHIP_PANEL_SPACING: f32 = 260.0;
+
+// ロックオンエネルギーのゲージ（ロックオン数の右）
+const ENERGY_GAUGE_X: f32 = 120.0;
+const ENERGY_GAUGE_Y: f32 = 44.0;
+const ENERGY_GAUGE_WIDTH: f32 = 120.0;
+const ENERGY_GAUGE_HEIGHT: f32 = 10.0;
+
+// 通常レーザーの熱・チャージのゲージ（ロックオン数の下）
+const WEAPON_GAUGE_X: f32 = 20.0;
+const WEAPON_GAUGE_Y: f32 = 64.0;
+const WEAPON_GAUGE_WIDTH: f32 = 80.0;
+const WEAPON_GAUGE_HEIGHT: f32 = 8.0;
+
+// ボムの残数表示（熱・チャージのゲージの下、残数分のアイコン）
+const BOMB_STOCK_X: f32 = 20.0;
+const BOMB_STOCK_Y: f32 = 88.0;
+const BOMB_ICON_RADIUS: f32 = 4.0;
+const BOMB_ICON_SPACING: f32 = 12.0;
+
+// 位置にある自機選択パネルの添字
+pub(super) fn ship_panel_at(position: Vec2) -> Option<usize> {
+    (0..SHIPS.len()).find(|&i| {
+        let x = SHIP_PANEL_X + i as f32 * SHIP_PANEL_SPACING;
+        (x..=x + SHIP_PANEL_WIDTH).contains(&position.x)
+            && (SHIP_PANEL_Y..=SHIP_PANEL_Y + SHIP_PANEL_HEIGHT).contains(&position.y)
+    })
+}
+
+impl Game {
+    pub(super) fn draw_title(&self, renderer: &mut dyn Renderer) {
+        // タイトル画面の背景（濃い青）
+        renderer.draw_rectangle(0.0, 0.0, 800.0, 600.0, Color::new(0.0, 0.0, 0.2, 1.0));
+        
+        // ゲームタイトル（中央上部、大きな文字）
+        let title_text = "TERRA LOCK";
+        let title_width = 48.0 * title_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            title_text,
+            (800.0 - title_width) / 2.0,
+            200.0,
+            48.0,
+            WHITE
+        );
+        
+        // サブタイトル（蒼穹紅蓮隊風ロックオンレーザーゲーム）
+        let subtitle_text = "Lock-on Laser Shooting Game";
+        let subtitle_width = 20.0 * subtitle_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            subtitle_text,
+            (800.0 - subtitle_width) / 2.0,
+            240.0,
+            20.0,
+            Color::new(0.8, 0.8, 1.0, 1.0) // 薄い青
+        );
+        
+        // 操作説明（中央）
+        let instructions = [
+            "HOW TO PLAY:",
+            "",
+            "Mouse: Move your ship",
+            "Click: Fire normal laser",
+            "Hold: Lock-on wireframe",
+            "Release: Fire homing lasers",
+            "Right click/Space: Bomb",
+            "",
+            "Destroy enemies to earn points!",
+            "Lock-on multiple enemies for bonus!"
+        ];
+        
+        for (i, instruction) in instructions.iter().enumerate() {
+            let y_pos = 320.0 + (i as f32 * 20.0);
+            let text_width = 16.0 * instruction.len() as f32 * 0.6;
+            renderer.draw_text(
+                instruction,
+                (800.0 - text_width) / 2.0,
+                y_pos,
+                16.0,
+                if instruction.is_empty() { Color::new(0.0, 0.0, 0.0, 0.0) } else { WHITE }
+            );
+        }
+        
+        // スタート指示（下部、点滅効果）
+        let time = self.time;
+        let alpha = (time * 2.0).sin() * 0.3 + 0.7; // 0.4〜1.0の範囲で点滅
+        let start_text = "Click to Start";
+        let start_width = 24.0 * start_text.len() as f32 * 0.6;
+        renderer.draw_text(
+            start_text,
+            (800.0 - start_width) / 2.0,
+            520.0,
+            24.0,
+            Color::new(1.0, 1.0, 0.0, alpha) // 黄色で点滅
+        );
+        
+        // 装飾的な星（背景）
+        for i in 0..20 {
+            let x = (i as f32 * 37.0) % 800.0;
+            let y = (i as f32 * 43.0 + time * 10.0) % 600.0;
+            let size = 1.0 + (i as f32 * 0.1) % 2.0;
+            renderer.draw_circle(x, y, size, Color::new(1.0, 1.0, 1.0, 0.3));
+        }
+    }
+    
+    pub(super) fn draw_ship_select(&self, renderer: &mut dyn Renderer) {
+        // 背景（タイトル画面と同じ濃い青）
+        renderer.draw_rectangle(0.0, 0.0, 800.0, 600.0, Color::new(0.0, 0.0, 0.2, 1.0));
+        
+        let heading_text = "SELECT YOUR SHIP";
+        let heading_width = 36.0 * heading_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(heading_text, (800.0 - heading_width) / 2.0, 90.0, 36.0, WHITE);
+        
+        // 自機ごとのパネル（マウスが乗っているパネルは黄色枠）
+        let hovered = ship_panel_at(self.input.mouse_pos);
+        for (i, ship) in SHIPS.iter().enumerate() {
+            let x = SHIP_PANEL_X + i as f32 * SHIP_PANEL_SPACING;
+            let y = SHIP_PANEL_Y;
+            let (border_color, border_thickness) = if hovered == Some(i) { (YELLOW, 4.0) } else { (Color::new(0.8, 0.8, 1.0, 1.0), 2.0) };
+            renderer.draw_rectangle(x, y, SHIP_PANEL_WIDTH, SHIP_PANEL_HEIGHT, Color::new(0.0, 0.0, 0.3, 0.8));
+            renderer.draw_rectangle_lines(x, y, SHIP_PANEL_WIDTH, SHIP_PANEL_HEIGHT, border_thickness, border_color);
+            
+            // 自機名と機体（自機の色の三角形）
+            renderer.draw_text(ship.name, x + 12.0, y + 32.0, 24.0, ship.color);
+            let icon = Vec2::new(x + SHIP_PANEL_WIDTH - 30.0, y + 24.0);
+            renderer.draw_triangle(icon + Vec2::new(0.0, -8.0), icon + Vec2::new(-11.0, 8.0), icon + Vec2::new(11.0, 8.0), ship.color);
+            
+            // 性能
+            let speed = if ship.speed.is_finite() { format!("{:.0} px/s", ship.speed) } else { "instant".to_string() };
+            let lines = [
+                format!("Speed: {}", speed),
+                format!("Shot: {}", ship.shot_pattern.name()),
+                format!("Lock: {} x{}", ship.lock_shape_name(), ship.max_targets),
+                format!("Homing: {:.0} px/s", ship.homing_max_speed),
+            ];
+            for (line_idx, line) in lines.iter().enumerate() {
+                renderer.draw_text(line, x + 12.0, y + 64.0 + line_idx as f32 * 22.0, 16.0, WHITE);
+            }
+            
+            // 自機別ハイスコア
+            renderer.draw_text("HIGH SCORES", x + 12.0, y + 172.0, 16.0, Color::new(0.8, 0.8, 1.0, 1.0));
+            let scores = self.high_scores[i].scores();
+            for rank in 0..HighScoreTable::CAPACITY {
+                let entry = match scores.get(rank) {
+                    Some(score) => format!("{}. {}", rank + 1, score),
+                    None => format!("{}. ---", rank + 1),
+                };
+                renderer.draw_text(&entry, x + 24.0, y + 196.0 + rank as f32 * 22.0, 16.0, GRAY);
+            }
+        }
+        
+        // 選択指示（点滅）
+        let alpha = (self.time * 2.0).sin() * 0.3 + 0.7;
+        let launch_text = "Click a ship to launch";
+        let launch_width = 24.0 * launch_text.len() as f32 * 0.6;
+        renderer.draw_text(
+            launch_text,
+            (800.0 - launch_width) / 2.0,
+            520.0,
+            24.0,
+            Color::new(1.0, 1.0, 0.0, alpha)
+        );
+    }
+    
+    // プレイ中のHUD（alpha は描画補間係数）
+    pub(super) fn draw_hud(&self, renderer: &mut dyn Renderer, alpha: f32) {
+        // UI表示 - スコア（16px monospace）
+        renderer.draw_text(&format!("SCORE: {}", self.score), 20.0, 30.0, 16.0, WHITE);
+        
+        // ロックオン数表示（ロックオン数に応じた色変化）
+        let lock_count = self.lock_system.locked_enemies.len();
+        let lock_color = match lock_count {
+            0 => GRAY,            // ロックオンなし: グレー
+            1..=2 => GREEN,       // 1-2機: 緑
+            3..=4 => YELLOW,      // 3-4機: 黄
+            5..=6 => ORANGE,      // 5-6機: オレンジ
+            _ => RED,             // 7機以上: 赤
+        };
+        renderer.draw_text(&format!("LOCK: {}/{}", lock_count, self.lock_system.max_targets), 20.0, 55.0, 16.0, lock_color);
+        
+        // ロックオンエネルギー（ロックオン済みのレーザーの消費予定分はオレンジ、次のロックオンができない場合は赤）
+        self.draw_energy_gauge(renderer);
+        
+        // 通常レーザーの状態（チャージ方式はチャージ量、それ以外は熱、過熱中は赤で点滅）
+        self.draw_weapon_gauge(renderer);
+        
+        // ボムの残数（なくなると灰色）
+        self.draw_bomb_stock(renderer);
+        
+        // ボーナススコア表示
+        for bonus_display in &self.bonus_displays {
+            let fade = bonus_display.get_alpha();
+            let color = Color::new(1.0, 1.0, 0.0, fade); // 黄色でフェードアウト
+            let position = bonus_display.previous_position.lerp(bonus_display.position, alpha);
+            renderer.draw_text(
+                &bonus_display.text,
+                position.x - 50.0, // 中央揃え調整
+                position.y,
+                24.0, // フォントサイズ
+                color
+            );
+        }
+
+        // 操作説明の表示（画面下部、小文字、薄いグレー）
+        let instruction_text = "Mouse: Move | Click: Shoot | Hold: Lock-on | Right click/Space: Bomb";
+        let text_width = 12.0 * instruction_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            instruction_text,
+            (800.0 - text_width) / 2.0, // 中央揃え
+            580.0, // 画面下部（600px - 20px）
+            12.0,  // 12px フォントサイズ
+            Color::new(0.7, 0.7, 0.7, 1.0) // 薄いグレー
+        );
+    }
+    
+    fn draw_energy_gauge(&self, renderer: &mut dyn Renderer) {
+        let lock_system = &self.lock_system;
+        let scale = ENERGY_GAUGE_WIDTH / LockOnSystem::MAX_ENERGY;
+        let energy_width = lock_system.energy * scale;
+        let reserved = (lock_system.energy_per_lock * lock_system.locked_enemies.len() as f32).min(lock_system.energy);
+        let color = if lock_system.can_afford_lock() { GREEN } else { RED };
+        
+        renderer.draw_rectangle_lines(ENERGY_GAUGE_X, ENERGY_GAUGE_Y, ENERGY_GAUGE_WIDTH, ENERGY_GAUGE_HEIGHT, 1.0, GRAY);
+        renderer.draw_rectangle(ENERGY_GAUGE_X, ENERGY_GAUGE_Y, energy_width - reserved * scale, ENERGY_GAUGE_HEIGHT, color);
+        if reserved > 0.0 {
+            renderer.draw_rectangle(
+                ENERGY_GAUGE_X + energy_width - reserved * scale,
+                ENERGY_GAUGE_Y,
+                reserved * scale,
+                ENERGY_GAUGE_HEIGHT,
+                ORANGE
+            );
+        }
+    }
+    
+    fn draw_weapon_gauge(&self, renderer: &mut dyn Renderer) {
+        let (label, level, color) = match self.config.fire_mode {
+            FireMode::Charge => {
+                // 貫通できるチャージ量に達すると白
+                let color = if NormalWeapon::charge_pierce(self.weapon.charge) > 0 { WHITE } else { SKYBLUE };
+                ("CHARGE", self.weapon.charge, color)
+            }
+            FireMode::Single | FireMode::Auto => {
+                let blink = (self.time * 8.0).sin() > 0.0;
+                let color = match (self.weapon.overheated, blink) {
+                    (true, true) => RED,
+                    (true, false) => Color::new(0.5, 0.0, 0.0, 1.0),
+                    (false, _) if self.weapon.heat >= 0.7 => ORANGE,
+                    (false, _) => SKYBLUE,
+                };
+                ("HEAT", self.weapon.heat, color)
+            }
+        };
+        
+        renderer.draw_text(label, WEAPON_GAUGE_X, WEAPON_GAUGE_Y + WEAPON_GAUGE_HEIGHT, 12.0, color);
+        let bar_x = WEAPON_GAUGE_X + 55.0;
+        renderer.draw_rectangle_lines(bar_x, WEAPON_GAUGE_Y, WEAPON_GAUGE_WIDTH, WEAPON_GAUGE_HEIGHT, 1.0, GRAY);
+        renderer.draw_rectangle(bar_x, WEAPON_GAUGE_Y, WEAPON_GAUGE_WIDTH * level.clamp(0.0, 1.0), WEAPON_GAUGE_HEIGHT, color);
+    }
+    
+    fn draw_bomb_stock(&self, renderer: &mut dyn Renderer) {
+        let color = if self.bombs > 0 { WHITE } else { GRAY };
+        renderer.draw_text("BOMB", BOMB_STOCK_X, BOMB_STOCK_Y + BOMB_ICON_RADIUS, 12.0, color);
+        for i in 0..self.bombs {
+            let x = BOMB_STOCK_X + 55.0 + BOMB_ICON_RADIUS + i as f32 * BOMB_ICON_SPACING;
+            renderer.draw_circle(x, BOMB_STOCK_Y, BOMB_ICON_RADIUS, ORANGE);
+        }
+    }
+    
+    pub(super) fn draw_demo_overlay(&self, renderer: &mut dyn Renderer) {
+        // デモ表示（中央上部、点滅）
+        let time = self.time;
+        let alpha = (time * 3.0).sin() * 0.4 + 0.6;
+        let demo_text = "DEMO";
+        let demo_width = 48.0 * demo_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            demo_text,
+            (800.0 - demo_width) / 2.0,
+            120.0,
+            48.0,
+            Color::new(1.0, 0.0, 0.0, alpha)
+        );
+        
+        let return_text = "Click to return to title";
+        let return_width = 16.0 * return_text.len() as f32 * 0.6;
+        renderer.draw_text(
+            return_text,
+            (800.0 - return_width) / 2.0,
+            150.0,
+            16.0,
+            YELLOW
+        );
+    }
+    
+    pub(super) fn draw_game_over(&self, renderer: &mut dyn Renderer) {
+        // 背景を暗くする
+        renderer.draw_rectangle(0.0, 0.0, 800.0, 600.0, Color::new(0.0, 0.0, 0.0, 0.7));
+        
+        // ゲームオーバー表示（中央上部、赤文字、48px）
+        let game_over_text = "GAME OVER";
+        let text_width = 48.0 * game_over_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            game_over_text,
+            (800.0 - text_width) / 2.0,
+            110.0,
+            48.0,
+            RED
+        );
+        
+        // 最終スコア表示
+        let score_text = format!("FINAL SCORE: {}", self.score);
+        let score_width = 24.0 * score_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            &score_text,
+            (800.0 - score_width) / 2.0,
+            155.0,
+            24.0,
+            WHITE
+        );
+        
+        // 統計サマリー（3パネル構成）
+        let stats = &self.stats;
+        let survival_lines = vec![
+            format!("Time: {:.1}s", stats.time_survived),
+            format!("Level: {}", stats.difficulty_level),
+            format!("Peak enemies: {}", stats.peak_enemies),
+            format!("Bombs used: {}", stats.bombs_used),
+        ];
+        
+        // 敵機タイプ別撃破数（パネルに収まるよう1行に2タイプ）
+        let mut combat_lines: Vec<String> = EnemyType::ALL
+            .chunks(2)
+            .map(|pair| {
+                pair.iter()
+                    .map(|enemy_type| format!("{}: {}", enemy_type.name(), stats.kills_by_type[enemy_type.index()]))
+                    .collect::<Vec<_>>()
+                    .join("  ")
+            })
+            .collect();
+        combat_lines.push(format!("Total kills: {}", stats.total_kills()));
+        combat_lines.push(format!(
+            "Accuracy: {:.0}% ({}/{})",
+            stats.accuracy() * 100.0, stats.normal_shots_hit, stats.normal_shots_fired
+        ));
+        
+        let mut lock_on_lines: Vec<String> = stats.volleys_by_size
+            .iter()
+            .enumerate()
+            .map(|(i, count)| {
+                // 最後の区分はそれ以上のロックオン数を含む
+                let plus = if i + 1 == VOLLEY_SIZE_BUCKETS { "+" } else { "" };
+                format!("{}{}-lock volleys: {}", i + 1, plus, count)
+            })
+            .collect();
+        lock_on_lines.push(format!("Bonus: +{}", stats.bonus_points));
+        
+        let panels = [
+            ("SURVIVAL", survival_lines),
+            ("COMBAT", combat_lines),
+            ("LOCK-ON", lock_on_lines),
+        ];
+        
+        for (i, (title, lines)) in panels.iter().enumerate() {
+            self.draw_stats_panel(renderer, 20.0 + i as f32 * 260.0, 190.0, title, lines);
+        }
+        
+        // 自機別ハイスコア（今回のスコアが1位なら強調）
+        let best = self.high_scores[self.ship].best().unwrap_or(0);
+        let new_record = self.score > 0 && self.score == best;
+        let best_text = if new_record {
+            format!("{} BEST: {}  NEW HIGH SCORE!", self.ship().name, best)
+        } else {
+            format!("{} BEST: {}", self.ship().name, best)
+        };
+        let best_width = 16.0 * best_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            &best_text,
+            (800.0 - best_width) / 2.0,
+            495.0,
+            16.0,
+            if new_record { YELLOW } else { WHITE }
+        );
+        
+        // リスタート指示（16px monospace）
+        let restart_text = "Click to Restart | E: Export stats (JSON)";
+        let restart_width = 16.0 * restart_text.len() as f32 * 0.6; // 概算幅
+        renderer.draw_text(
+            restart_text,
+            (800.0 - restart_width) / 2.0,
+            520.0,
+            16.0, // 16px統一
+            YELLOW
+        );
+    }
+    
+    fn draw_stats_panel(&self, renderer: &mut dyn Renderer, x: f32, y: f32, title: &str, lines: &[String]) {
+        // パネル枠（幅240px、高さ280px）
+        let width = 240.0;
+        let height = 280.0;
+        renderer.draw_rectangle(x, y, width, height, Color::new(0.0, 0.0, 0.2, 0.8));
+        renderer.draw_rectangle_lines(x, y, width, height, 2.0, Color::new(0.8, 0.8, 1.0, 1.0));
+        
+        // パネルタイトル
+        renderer.draw_text(title, x + 12.0, y + 28.0, 20.0, Color::new(0.8, 0.8, 1.0, 1.0));
+        
+        // 統計項目（16px）
+        for (i, line) in lines.iter().enumerate() {
+            renderer.draw_text(line, x + 12.0, y + 60.0 + i as f32 * 24.0, 16.0, WHITE);
+        }
+    }
+    
+    pub(super) fn export_stats(&self) {
+        // ネイティブ環境ではファイル出力、WebAssembly環境ではonGameOverコールバックで取得
+        let json = self.stats.to_json();
+        match std::fs::write("terra_lock_stats.json", &json) {
+            Ok(()) => game_log!("Stats exported to terra_lock_stats.json"),
+            Err(err) => game_log!("Stats export failed ({}): {}", err, json),
+        }
+    }
+    
+    pub fn draw_debug_info(&self, renderer: &mut dyn Renderer, fps: f32) {
+        let screen_width = 800.0;
+        
+        // FPS表示（パフォーマンス監視）- 14px monospace
+        let fps_color = if fps >= 60.0 { GREEN } else if fps >= 45.0 { YELLOW } else { RED };
+        renderer.draw_text(&format!("FPS: {:.1}", fps), screen_width - 100.0, 30.0, 14.0, fps_color);
+        
+        // マウス座標表示 - 14px monospace
+        renderer.draw_text(
+            &format!("Mouse: ({:.0}, {:.0})", self.input.mouse_pos.x, self.input.mouse_pos.y),
+            screen_width - 200.0, 55.0, 14.0, WHITE
+        );
+        
+        // マウスボタン状態表示 - 14px monospace
+        let button_status = if self.input.left_button_pressed {
+            if self.input.is_long_press(self.config.long_press_time) {
+                format!("LONG PRESS ({:.1}s)", self.input.left_button_hold_time)
+            } else {
+                "PRESSED".to_string()
+            }
+        } else {
+            "RELEASED".to_string()
+        };
+        
+        let button_color = if self.input.left_button_pressed {
+            if self.input.is_long_press(self.config.long_press_time) { ORANGE } else { GREEN }
+        } else { WHITE };
+        
+        renderer.draw_text(
+            &format!("Button: {}", button_status),
+            screen_width - 250.0, 80.0, 14.0, button_color
+        );
+        
+        // 難易度情報表示 - 14px monospace
+        let (spawn_interval, max_spawn, speed_mult) = self.calculate_difficulty_parameters();
+        let elapsed_time = self.time - self.game_start_time;
+        renderer.draw_text(
+            &format!("Time: {:.1}s | Interval: {:.1}s | Max: {} | Speed: {:.1}x", 
+                elapsed_time, spawn_interval, max_spawn, speed_mult),
+            screen_width - 400.0, 105.0, 14.0, YELLOW
+        );
+        
+        // オブジェクトプール使用状況（使用数/容量、最大使用数、容量不足回数）
+        for (i, (name, metrics)) in self.pool_metrics().iter().enumerate() {
+            let pool_color = if metrics.overflow > 0 { RED } else { SKYBLUE };
+            renderer.draw_text(
+                &format!("{}: {}/{} peak {} overflow {}",
+                    name, metrics.active, metrics.capacity, metrics.peak, metrics.overflow),
+                screen_width - 250.0, 130.0 + i as f32 * 18.0, 14.0, pool_color
+            );
+        }
+    }
+}
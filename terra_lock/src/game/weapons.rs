@@ -0,0 +1,748 @@
+// 武器（通常レーザー・ホーミングレーザーの発射、移動、敵機との当たり判定）
+
+use macroquad::prelude::*;
+
+use super::scoring::{Volley, NORMAL_LASER_SCORE};
+use super::trajectory::{self, Trail, Trajectory, TrajectoryMode, STEERING_LIFETIME};
+use super::{EnemyType, Game, GameConfig, GameEvent, ShotPattern};
+use crate::collision::{self, Hitbox};
+use crate::pool;
+
+// 当たり判定の既定形状（エンティティごとに保持）
+const NORMAL_LASER_RADIUS: f32 = 1.5;                                 // 通常レーザー（幅3px、円）
+const HOMING_HEAD_HITBOX: Hitbox = Hitbox::Circle { radius: 2.0 };   // ホーミングレーザー先端（幅2px）
+
+// 通常レーザーの熱（1発ごとの上昇量と1秒あたりの冷却量、1.0で過熱し0まで冷えるまで発射不可）
+const HEAT_PER_SHOT: f32 = 0.08;
+const HEAT_COOL_RATE: f32 = 0.6;
+
+// チャージショット（最大チャージで当たり判定の半径に加える大きさと、撃破して貫通できる敵機数）
+const CHARGE_EXTRA_RADIUS: f32 = 4.5;
+const CHARGE_MAX_PIERCE: f32 = 3.0;
+
+// 通常ショットの発射パターンの寸法
+const SPREAD_ANGLE: f32 = 0.2;       // 拡散ショットの左右の角度（ラジアン）
+const WIDE_SHOT_OFFSET: f32 = 8.0;   // 平行ショットの自機中心からの横方向の間隔
+
+// ホーミングレーザーの既定の軌道の高さ（開始点と目標点の中間から上方へ）
+const DEFAULT_ARC_HEIGHT: f32 = 100.0;
+
+// 一斉発射のレーザーを自機から扇状に広げる角度（真上から左右へ、ラジアン）と乱数による揺らぎ
+const FAN_HALF_ANGLE: f32 = 1.2;
+const FAN_JITTER: f32 = 0.15;
+
+// 同じ敵機へ重ねたロックオンのレーザーの発射間隔（秒）
+const STACK_LAUNCH_INTERVAL: f32 = 0.05;
+
+// 掃引判定の検索範囲に加える余裕（敵機の1ステップの移動量を上回る値）
+const COLLISION_QUERY_MARGIN: f32 = 16.0;
+
+// 目標を変更した曲線方式のレーザーの、現在の進行方向への第1制御点の距離
+const RETARGET_LAUNCH_LENGTH: f32 = 60.0;
+
+// 目標の敵機を失ったホーミングレーザーの動作（撃破・画面外への退場時）
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TargetLossMode {
+    Continue,  // 最後の目標位置まで飛行し、終点にいる敵機に命中
+    Retarget,  // ロックオンされていない最寄りの敵機へ目標を変更（いなければ Continue と同じ）
+    Fizzle,    // その場で消滅
+}
+
+impl TargetLossMode {
+    // 設定値のID（ホストページの setConfig 用）
+    pub fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(TargetLossMode::Continue),
+            1 => Some(TargetLossMode::Retarget),
+            2 => Some(TargetLossMode::Fizzle),
+            _ => None,
+        }
+    }
+}
+
+// 命中せずに消えたホーミングレーザーの消滅エフェクト
+#[derive(Clone, Debug, Default)]
+pub struct LaserFizzle {
+    pub position: Vec2,
+    pub timer: f32,
+}
+
+impl LaserFizzle {
+    pub const DURATION: f32 = 0.3;
+    
+    // エフェクトの進行度（0.0〜1.0）
+    pub fn progress(&self) -> f32 {
+        (self.timer / Self::DURATION).min(1.0)
+    }
+}
+
+// 通常レーザーの発射方式
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FireMode {
+    Single,  // 押すごとに1回
+    Auto,    // 長押し判定までの押下中に連射
+    Charge,  // 長押し判定までの押下時間でチャージし、離した時に発射
+}
+
+impl FireMode {
+    // 設定値のID（ホストページの setConfig 用）
+    pub fn from_id(id: u32) -> Option<Self> {
+        match id {
+            0 => Some(FireMode::Single),
+            1 => Some(FireMode::Auto),
+            2 => Some(FireMode::Charge),
+            _ => None,
+        }
+    }
+}
+
+// 通常レーザーの発射装置（発射間隔・熱・チャージ）
+#[derive(Clone, Debug, Default)]
+pub struct NormalWeapon {
+    pub cooldown: f32,     // 次に発射できるまでの時間（秒）
+    pub heat: f32,         // 熱（0.0〜1.0）
+    pub overheated: bool,  // 過熱中（熱が0に戻るまで発射不可）
+    pub charge: f32,       // チャージ量（0.0〜1.0、長押し判定に達すると0に戻る）
+}
+
+impl NormalWeapon {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    // チャージ量に応じた、撃破して貫通できる敵機数
+    pub fn charge_pierce(charge: f32) -> u8 {
+        (CHARGE_MAX_PIERCE * charge) as u8
+    }
+    
+    pub fn can_fire(&self) -> bool {
+        self.cooldown <= 0.0 && !self.overheated
+    }
+    
+    // 発射間隔の経過と冷却
+    fn cool(&mut self, delta_time: f32) {
+        self.cooldown = (self.cooldown - delta_time).max(0.0);
+        self.heat = (self.heat - HEAT_COOL_RATE * delta_time).max(0.0);
+        if self.heat <= 0.0 {
+            self.overheated = false;
+        }
+    }
+    
+    // 発射を記録（発射間隔の開始と熱の上昇）
+    fn record_shot(&mut self, fire_interval: f32) {
+        self.cooldown = fire_interval;
+        self.heat += HEAT_PER_SHOT;
+        if self.heat >= 1.0 {
+            self.heat = 1.0;
+            self.overheated = true;
+        }
+    }
+}
+
+// 通常レーザー構造体
+#[derive(Clone, Debug)]
+pub struct NormalLaser {
+    pub position: Vec2,
+    pub previous_position: Vec2, // 前回ステップの位置（描画補間用・掃引判定用）
+    pub hitbox: Hitbox,
+    pub velocity: Vec2,
+    pub lifetime: f32,
+    pub pierce: u8,  // 撃破して貫通できる残りの敵機数（チャージショット）
+    pub hit: bool,   // 敵機に命中済み（命中率の集計用）
+}
+
+// ロックオンレーザー構造体
+#[derive(Clone, Debug)]
+pub struct LockOnLaser {
+    pub start_pos: Vec2,
+    pub position: Vec2,             // レーザー先端の位置
+    pub previous_position: Vec2,    // 前回ステップの先端位置（描画補間用・掃引判定用）
+    pub target_pos: Vec2,
+    pub target_enemy_id: Option<usize>, // 追跡対象の敵機ID
+    pub progress: f32,              // 軌道の進行度（誘導方式では寿命に対する経過時間）
+    pub previous_progress: f32,     // 前回ステップの進行度
+    pub previous_target_pos: Vec2,  // 前回ステップのターゲット位置（描画補間用）
+    pub hitbox: Hitbox,             // 先端の当たり判定形状
+    pub hit: bool,                  // 対象敵機に命中済み
+    pub initial_speed: f32,    // 初期速度
+    pub current_speed: f32,    // 現在の速度
+    pub acceleration: f32,     // 加速度
+    pub launch_time: f32,      // 発射時刻（重ねたロックオンのレーザーは発射まで自機位置で待機）
+    pub volley: u32,           // 発射した一斉発射の番号
+    pub mode: TrajectoryMode,
+    pub trajectory: Trajectory, // 曲線方式の軌道
+    pub heading: Vec2,         // 誘導方式の進行方向（単位ベクトル）
+    pub turn_rate: f32,        // 誘導方式の旋回速度（ラジアン/秒、発射直後の値）
+    pub trail: Trail,          // 先端位置の履歴（描画用）
+    pub on_target_loss: TargetLossMode, // 目標の敵機を失った場合の動作
+}
+
+impl LockOnLaser {
+    // 自機位置から敵機へ向けて発射（速度・加速度・軌道の方式はゲーム設定から、軌道は既定の放物線）
+    pub fn new(start_pos: Vec2, target_pos: Vec2, target_enemy_id: Option<usize>, config: &GameConfig, launch_time: f32) -> Self {
+        let trajectory = Trajectory::arc(start_pos, target_pos, DEFAULT_ARC_HEIGHT);
+        Self {
+            start_pos,
+            position: start_pos,
+            previous_position: start_pos,
+            target_pos,
+            target_enemy_id,
+            progress: 0.0,
+            previous_progress: 0.0,
+            previous_target_pos: target_pos,
+            hitbox: HOMING_HEAD_HITBOX,
+            hit: false,
+            initial_speed: config.homing_initial_speed,
+            current_speed: config.homing_initial_speed,
+            acceleration: config.homing_acceleration,
+            launch_time,
+            volley: 0,
+            mode: config.homing_trajectory,
+            trajectory,
+            heading: trajectory.launch_offset.normalize_or(Vec2::NEG_Y),
+            turn_rate: config.homing_turn_rate,
+            trail: Trail::new(start_pos),
+            on_target_loss: config.homing_target_loss,
+        }
+    }
+    
+    // 軌道を設定（誘導方式の初期方向は曲線の発射方向）
+    pub fn set_trajectory(&mut self, trajectory: Trajectory) {
+        self.trajectory = trajectory;
+        self.heading = trajectory.launch_offset.normalize_or(Vec2::NEG_Y);
+    }
+    
+    // 目標を変更（曲線方式は現在位置から現在の進行方向へ膨らむ曲線を引き直す）
+    pub fn retarget(&mut self, target_enemy_id: usize, target_pos: Vec2) {
+        self.target_enemy_id = Some(target_enemy_id);
+        self.target_pos = target_pos;
+        if self.mode == TrajectoryMode::Curve {
+            let direction = (self.position - self.previous_position).normalize_or(self.heading);
+            self.start_pos = self.position;
+            self.progress = 0.0;
+            self.trajectory = Trajectory {
+                launch_offset: direction * RETARGET_LAUNCH_LENGTH,
+                arrival_offset: Vec2::ZERO,
+            };
+        }
+    }
+    
+    // 発射済みか（発射時刻に達したか）
+    pub fn is_launched(&self, time: f32) -> bool {
+        time >= self.launch_time
+    }
+    
+    // 現在のレーザー先端位置
+    pub fn head(&self) -> Vec2 {
+        self.position
+    }
+    
+    // 先端を進める（elapsed_time は発射からの経過時間）
+    fn advance(&mut self, delta_time: f32, elapsed_time: f32) {
+        match self.mode {
+            TrajectoryMode::Curve => {
+                // 進行度を更新（加速を考慮した距離計算）
+                let distance = self.trajectory.approximate_length(self.start_pos, self.target_pos);
+                if distance > 0.0 {
+                    let speed_progress = self.current_speed * delta_time / distance;
+                    self.progress += speed_progress;
+                    self.progress = self.progress.min(1.0);
+                }
+                self.position = self.trajectory.point(self.start_pos, self.target_pos, self.progress);
+            }
+            TrajectoryMode::Steering => {
+                let step = self.current_speed * delta_time;
+                let to_target = self.target_pos - self.position;
+                
+                // 目標を失ったレーザーは最後の目標位置に到達した時点で完了
+                if self.target_enemy_id.is_none() && to_target.length() <= step {
+                    self.position = self.target_pos;
+                    self.progress = 1.0;
+                    return;
+                }
+                
+                let max_turn = trajectory::steering_turn_rate(self.turn_rate, elapsed_time) * delta_time;
+                self.heading = trajectory::turn_towards(self.heading, to_target.normalize_or(self.heading), max_turn);
+                self.position += self.heading * step;
+                self.progress = (elapsed_time / STEERING_LIFETIME).min(1.0);
+            }
+        }
+    }
+}
+
+impl Game {
+    // ホーミングレーザーのターゲットID調整（敵機削除前に呼び出す）
+    pub(super) fn update_homing_laser_targets(&mut self, destroyed_indices: &[usize]) {
+        let enemy_count = self.enemies.len();
+        for laser in &mut self.lock_on_lasers {
+            if let Some(target_id) = laser.target_enemy_id {
+                // 撃破された敵機をターゲットにしている場合はIDをクリア、
+                // 末尾から移動する敵機をターゲットにしている場合は移動先のIDに調整
+                laser.target_enemy_id = pool::remap_after_swap_remove(target_id, destroyed_indices, enemy_count);
+            }
+        }
+    }
+    
+    // 通常レーザーの移動と寿命管理
+    pub(super) fn update_normal_lasers(&mut self, delta_time: f32) {
+        for laser in &mut self.normal_lasers {
+            laser.position += laser.velocity * delta_time;
+            laser.lifetime -= delta_time;
+        }
+        
+        // 寿命切れまたは画面外のレーザーを削除
+        self.normal_lasers.retain(|laser| laser.lifetime > 0.0 && laser.position.y > -50.0);
+    }
+    
+    // ホーミングレーザーの更新（動的ターゲット追跡 + 加速）
+    pub(super) fn update_homing_lasers(&mut self, delta_time: f32) {
+        let current_time = self.time;
+        let player_pos = self.player.position;
+        
+        // 目標を失ったレーザーの目標変更（目標変更の設定のレーザーのみ）
+        self.retarget_homing_lasers();
+        
+        for laser in &mut self.lock_on_lasers {
+            // 対象敵機が存在する場合、ターゲット位置を更新
+            if let Some(enemy_id) = laser.target_enemy_id {
+                if enemy_id < self.enemies.len() {
+                    // 敵機の現在位置にターゲットを更新
+                    laser.target_pos = self.enemies[enemy_id].position;
+                } else {
+                    // 対象敵機が削除された場合、IDをクリア
+                    laser.target_enemy_id = None;
+                }
+            }
+            
+            // 目標を失って消滅するレーザーは命中なしで完了
+            if laser.target_enemy_id.is_none() && laser.on_target_loss == TargetLossMode::Fizzle {
+                laser.progress = 1.0;
+                continue;
+            }
+            
+            // 発射待ちのレーザーは自機とともに移動
+            if !laser.is_launched(current_time) {
+                laser.start_pos = player_pos;
+                laser.position = player_pos;
+                laser.trail = Trail::new(player_pos);
+                continue;
+            }
+            
+            // 加速処理：時間経過に応じて速度を増加
+            let elapsed_time = current_time - laser.launch_time;
+            laser.current_speed = laser.initial_speed + (laser.acceleration * elapsed_time);
+            
+            // 最大速度制限（既定560px/秒）
+            laser.current_speed = laser.current_speed.min(self.config.homing_max_speed);
+            
+            // 軌道の方式に応じて先端を進め、軌跡に記録
+            laser.advance(delta_time, elapsed_time);
+            laser.trail.push(laser.position);
+        }
+        
+        // レーザー先端と対象敵機の当たり判定（前回ステップの先端位置からの掃引）
+        for laser_idx in 0..self.lock_on_lasers.len() {
+            let laser = &self.lock_on_lasers[laser_idx];
+            let Some(target_id) = laser.target_enemy_id else {
+                continue;
+            };
+            if !laser.is_launched(current_time) {
+                continue;
+            }
+            let enemy = &self.enemies[target_id];
+            
+            if collision::swept_overlaps(laser.previous_position, laser.position, laser.hitbox, enemy.hitbox, enemy.position) {
+                self.lock_on_lasers[laser_idx].hit = true;
+            }
+        }
+        
+        // 目標を失ったまま終点に到達したレーザーは、終点にいる敵機に命中
+        self.hit_enemies_at_endpoints();
+        
+        // 完了したホーミングレーザー（命中または軌道終端に到達）の処理と敵機削除
+        let mut completed_count = 0;
+        let mut hit_count = 0;
+        self.enemies_to_remove.clear();
+        
+        // 命中したレーザーの対象敵機の耐久力を減らし、0になった敵機を収集
+        // 命中しなかったレーザーは得点なしで消滅エフェクトを表示
+        for laser in self.lock_on_lasers.iter().filter(|laser| laser.hit || laser.progress >= 1.0) {
+            completed_count += 1;
+            let hit_target = laser.target_enemy_id.filter(|_| laser.hit);
+            if let Some(volley) = self.volleys.iter_mut().find(|volley| volley.id == laser.volley) {
+                volley.remaining -= 1;
+                if hit_target.is_some() {
+                    volley.hits += 1;
+                }
+            }
+            match hit_target {
+                Some(target_id) => {
+                    hit_count += 1;
+                    let enemy = &mut self.enemies[target_id];
+                    enemy.hp = enemy.hp.saturating_sub(1);
+                    if enemy.hp == 0 {
+                        self.enemies_to_remove.push(target_id);
+                    }
+                }
+                None => {
+                    self.laser_fizzles.spawn(LaserFizzle { position: laser.position, timer: 0.0 });
+                }
+            }
+        }
+        
+        if completed_count > 0 {
+            // 対象敵機を削除
+            self.remove_enemies(true);
+            
+            // ロックオンレーザー撃破スコアと、全レーザーが完了した一斉発射の同時撃破ボーナス
+            if hit_count > 0 {
+                self.award_lock_on_hits(hit_count);
+            }
+            self.award_completed_volleys();
+        }
+        
+        self.lock_on_lasers.retain(|laser| !laser.hit && laser.progress < 1.0);
+    }
+    
+    // 目標を失ったレーザーを、ロックオンされておらず他のレーザーも追っていない最寄りの敵機へ向ける
+    fn retarget_homing_lasers(&mut self) {
+        for laser_idx in 0..self.lock_on_lasers.len() {
+            let laser = &self.lock_on_lasers[laser_idx];
+            if laser.target_enemy_id.is_some() || laser.hit || laser.on_target_loss != TargetLossMode::Retarget {
+                continue;
+            }
+            
+            let position = laser.position;
+            let lasers = &self.lock_on_lasers;
+            let new_target = self.enemies
+                .iter()
+                .enumerate()
+                .filter(|&(enemy_idx, enemy)| {
+                    !enemy.is_locked && !lasers.iter().any(|other| other.target_enemy_id == Some(enemy_idx))
+                })
+                .min_by(|&(a, enemy_a), &(b, enemy_b)| {
+                    let distance_a = enemy_a.position.distance_squared(position);
+                    let distance_b = enemy_b.position.distance_squared(position);
+                    distance_a.total_cmp(&distance_b).then(a.cmp(&b))
+                })
+                .map(|(enemy_idx, _)| enemy_idx);
+            
+            // 対象がいない場合は目標なしのまま飛行を続け、次のステップで再検索
+            if let Some(enemy_idx) = new_target {
+                let target_pos = self.enemies[enemy_idx].position;
+                self.lock_on_lasers[laser_idx].retarget(enemy_idx, target_pos);
+            }
+        }
+    }
+    
+    // 目標を失って終点に到達したレーザーの最後のステップの移動線分に重なる敵機を命中対象にする
+    fn hit_enemies_at_endpoints(&mut self) {
+        let max_enemy_radius = EnemyType::max_hitbox_radius();
+        let mut grid_ready = false;
+        
+        for laser_idx in 0..self.lock_on_lasers.len() {
+            let laser = &self.lock_on_lasers[laser_idx];
+            if laser.target_enemy_id.is_some() || laser.progress < 1.0 || laser.on_target_loss == TargetLossMode::Fizzle {
+                continue;
+            }
+            let (sweep_start, sweep_end, hitbox) = (laser.previous_position, laser.position, laser.hitbox);
+            
+            if !grid_ready {
+                self.rebuild_enemy_grid();
+                grid_ready = true;
+            }
+            let query_center = (sweep_start + sweep_end) / 2.0;
+            let query_radius = sweep_start.distance(sweep_end) / 2.0
+                + hitbox.bounding_radius()
+                + max_enemy_radius
+                + COLLISION_QUERY_MARGIN;
+            self.query_buffer.clear();
+            self.enemy_grid.query_circle(query_center, query_radius, &mut self.query_buffer);
+            
+            // 複数重なる場合は終点に近い敵機を優先
+            let hit_enemy = self.query_buffer
+                .iter()
+                .copied()
+                .filter(|&enemy_idx| {
+                    let enemy = &self.enemies[enemy_idx];
+                    collision::swept_overlaps(sweep_start, sweep_end, hitbox, enemy.hitbox, enemy.position)
+                })
+                .min_by(|&a, &b| {
+                    let distance_a = self.enemies[a].position.distance_squared(sweep_end);
+                    let distance_b = self.enemies[b].position.distance_squared(sweep_end);
+                    distance_a.total_cmp(&distance_b).then(a.cmp(&b))
+                });
+            
+            if let Some(enemy_idx) = hit_enemy {
+                let laser = &mut self.lock_on_lasers[laser_idx];
+                laser.target_enemy_id = Some(enemy_idx);
+                laser.hit = true;
+            }
+        }
+    }
+    
+    // 消滅エフェクトの更新と期限切れの削除
+    pub(super) fn update_laser_fizzles(&mut self, delta_time: f32) {
+        for fizzle in &mut self.laser_fizzles {
+            fizzle.timer += delta_time;
+        }
+        self.laser_fizzles.retain(|fizzle| fizzle.timer < LaserFizzle::DURATION);
+    }
+    
+    pub(super) fn fire_lock_on_lasers(&mut self) {
+        let player_pos = self.player.position;
+        let current_time = self.time;
+        let volley = self.next_volley_id;
+        self.next_volley_id = self.next_volley_id.wrapping_add(1);
+        let mut launched_count = 0;
+        
+        let locked_enemies = &self.lock_system.locked_enemies;
+        let laser_count = locked_enemies.len();
+        for (lock_idx, &enemy_idx) in locked_enemies.iter().enumerate() {
+            if enemy_idx < self.enemies.len() {
+                let target_pos = self.enemies[enemy_idx].position;
+                
+                // 同じ敵機に重ねたロックオンは時間差で発射
+                let stack_index = locked_enemies[..lock_idx].iter().filter(|&&idx| idx == enemy_idx).count();
+                let launch_time = current_time + stack_index as f32 * STACK_LAUNCH_INTERVAL;
+                
+                // 敵機IDを設定して追跡（自機から扇状に広がってから目標へ収束）
+                let mut laser = LockOnLaser::new(player_pos, target_pos, Some(enemy_idx), &self.config, launch_time);
+                laser.set_trajectory(self.fan_trajectory(player_pos, target_pos, lock_idx, laser_count));
+                laser.volley = volley;
+                if self.lock_on_lasers.spawn(laser).is_some() {
+                    launched_count += 1;
+                }
+            }
+        }
+        
+        // 統計・通知は実際に発射したレーザー数（無効な添字やプールの上限で発射できなかった分は含めない）
+        if launched_count > 0 {
+            self.volleys.push(Volley { id: volley, remaining: launched_count, hits: 0 });
+            self.stats.record_volley(launched_count);
+            self.emit(GameEvent::LocksFired(launched_count));
+            game_log!("Fired {} lock-on lasers!", launched_count);
+        }
+    }
+    
+    // 一斉発射の lock_idx 番目のレーザーの軌道（発射方向は扇の左から右へ、長さと進入方向は乱数で揺らす）
+    fn fan_trajectory(&self, start: Vec2, target: Vec2, lock_idx: usize, laser_count: usize) -> Trajectory {
+        let rng = &self.trajectory_rng;
+        let spread = if laser_count > 1 { lock_idx as f32 / (laser_count - 1) as f32 * 2.0 - 1.0 } else { 0.0 };
+        let angle = spread * FAN_HALF_ANGLE + rng.gen_range(-FAN_JITTER, FAN_JITTER);
+        let launch_length = (start.distance(target) * 0.5).clamp(60.0, 200.0) * rng.gen_range(0.8, 1.2);
+        Trajectory {
+            launch_offset: Vec2::from_angle(angle).rotate(Vec2::NEG_Y) * launch_length,
+            arrival_offset: Vec2::new(rng.gen_range(-60.0, 60.0), rng.gen_range(-120.0, -40.0)),
+        }
+    }
+    
+    // 通常レーザーの発射（発射方式に応じて短押し・長押し判定前の連射・チャージ、発射間隔と熱で制限）
+    pub(super) fn update_normal_weapon(&mut self, delta_time: f32) {
+        self.weapon.cool(delta_time);
+        // 長押し判定の前（エネルギー切れでワイヤーフレームが開かない場合も長押し判定で停止）
+        let before_lock_threshold = !self.input.is_long_press(self.config.long_press_time);
+        let pressed_before_lock = self.input.left_button_pressed && before_lock_threshold;
+        
+        match self.config.fire_mode {
+            FireMode::Single => {
+                if self.input.left_button_just_pressed && !self.lock_system.active {
+                    self.try_fire_normal_laser(0.0);
+                }
+            }
+            FireMode::Auto => {
+                if pressed_before_lock {
+                    self.try_fire_normal_laser(0.0);
+                }
+            }
+            FireMode::Charge => {
+                if pressed_before_lock {
+                    self.weapon.charge = (self.input.left_button_hold_time / self.config.long_press_time).min(1.0);
+                } else if !before_lock_threshold {
+                    // 長押し判定に達した場合はロックオンへ移行し、チャージは破棄
+                    self.weapon.charge = 0.0;
+                }
+                
+                if self.input.left_button_just_released && self.weapon.charge > 0.0 {
+                    let charge = std::mem::take(&mut self.weapon.charge);
+                    self.try_fire_normal_laser(charge);
+                }
+            }
+        }
+    }
+    
+    fn try_fire_normal_laser(&mut self, charge: f32) {
+        if self.weapon.can_fire() {
+            self.fire_normal_laser(charge);
+            self.weapon.record_shot(self.config.fire_interval);
+        }
+    }
+    
+    fn fire_normal_laser(&mut self, charge: f32) {
+        // プレイヤーの位置から自機の発射パターンでレーザーを発射（既定は上向き1発）
+        let speed = self.config.normal_laser_speed;
+        match self.ship().shot_pattern {
+            ShotPattern::Single => self.spawn_normal_laser(Vec2::ZERO, Vec2::new(0.0, -speed), charge),
+            ShotPattern::Spread => {
+                for angle in [-SPREAD_ANGLE, 0.0, SPREAD_ANGLE] {
+                    self.spawn_normal_laser(Vec2::ZERO, Vec2::new(angle.sin(), -angle.cos()) * speed, charge);
+                }
+            }
+            ShotPattern::Wide => {
+                for offset_x in [-WIDE_SHOT_OFFSET, WIDE_SHOT_OFFSET] {
+                    self.spawn_normal_laser(Vec2::new(offset_x, 0.0), Vec2::new(0.0, -speed), charge);
+                }
+            }
+        }
+    }
+    
+    // チャージ量に応じて当たり判定を大きくし、撃破した敵機を貫通できるようにする
+    fn spawn_normal_laser(&mut self, offset: Vec2, velocity: Vec2, charge: f32) {
+        let position = self.player.position + offset;
+        let spawned = self.normal_lasers.spawn(NormalLaser {
+            position,
+            previous_position: position,
+            hitbox: Hitbox::Circle { radius: NORMAL_LASER_RADIUS + CHARGE_EXTRA_RADIUS * charge },
+            velocity,
+            lifetime: 3.0, // 3秒間の寿命
+            pierce: NormalWeapon::charge_pierce(charge),
+            hit: false,
+        });
+        if spawned.is_some() {
+            self.stats.normal_shots_fired += 1;
+        }
+    }
+    
+    pub(super) fn check_laser_enemy_collision(&mut self) {
+        self.lasers_to_remove.clear();
+        self.enemies_to_remove.clear();
+        
+        let max_enemy_radius = EnemyType::max_hitbox_radius();
+        self.rebuild_enemy_grid();
+        
+        for laser_idx in 0..self.normal_lasers.len() {
+            let laser = &self.normal_lasers[laser_idx];
+            
+            // このステップの移動線分の周辺セルの敵機のみ判定
+            let query_center = (laser.previous_position + laser.position) / 2.0;
+            let query_radius = laser.previous_position.distance(laser.position) / 2.0
+                + laser.hitbox.bounding_radius()
+                + max_enemy_radius
+                + COLLISION_QUERY_MARGIN;
+            self.query_buffer.clear();
+            self.enemy_grid.query_circle(query_center, query_radius, &mut self.query_buffer);
+            
+            // 移動線分による掃引判定（敵機から見た相対移動で判定し、すれ違いも検出）
+            // このレーザーは1つの敵にのみ当たる（移動開始位置に近い敵機を優先）
+            // 同じステップで別のレーザーが撃破済みの敵機は対象外（複数発の同時命中で二重加算しない）
+            let hit_enemy = self.query_buffer
+                .iter()
+                .copied()
+                .filter(|enemy_idx| !self.enemies_to_remove.contains(enemy_idx))
+                .filter(|&enemy_idx| {
+                    let enemy = &self.enemies[enemy_idx];
+                    let sweep_start = laser.previous_position + (enemy.position - enemy.previous_position);
+                    collision::swept_overlaps(sweep_start, laser.position, laser.hitbox, enemy.hitbox, enemy.position)
+                })
+                .min_by(|&a, &b| {
+                    let distance_a = self.enemies[a].position.distance_squared(laser.previous_position);
+                    let distance_b = self.enemies[b].position.distance_squared(laser.previous_position);
+                    distance_a.total_cmp(&distance_b).then(a.cmp(&b))
+                });
+            
+            if let Some(enemy_idx) = hit_enemy {
+                // シールド型はダメージなしでレーザーを消す（命中率の集計対象外）
+                if self.enemies[enemy_idx].enemy_type.blocks_normal_lasers() {
+                    self.lasers_to_remove.push(laser_idx);
+                    continue;
+                }
+                
+                // 当たり判定発生（耐久力が0になった敵機を撃破）
+                let enemy = &mut self.enemies[enemy_idx];
+                enemy.hp = enemy.hp.saturating_sub(1);
+                let destroyed = enemy.hp == 0;
+                if destroyed {
+                    self.enemies_to_remove.push(enemy_idx);
+                    
+                    // 通常レーザー撃破時のスコア加算（100点）
+                    self.score += NORMAL_LASER_SCORE;
+                }
+                
+                // 初めての命中を集計し、貫通できない場合（撃破できなかった場合を含む）はレーザーを削除
+                let laser = &mut self.normal_lasers[laser_idx];
+                if !laser.hit {
+                    laser.hit = true;
+                    self.stats.normal_shots_hit += 1;
+                }
+                if destroyed && laser.pierce > 0 {
+                    laser.pierce -= 1;
+                } else {
+                    self.lasers_to_remove.push(laser_idx);
+                }
+            }
+        }
+        
+        // 逆順で削除（各レーザーは昇順で1回のみ登録済み）
+        for &idx in self.lasers_to_remove.iter().rev() {
+            self.normal_lasers.swap_remove(idx);
+        }
+        
+        // 撃破された敵機の削除（ロックオン解除・ターゲットID調整を含む）
+        self.remove_enemies(true);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::test_support::{game_with_enemies, homing_laser};
+    
+    #[test]
+    fn fire_lock_on_lasers_counts_only_launched_lasers() {
+        let mut game = game_with_enemies(3);
+        game.lock_system.locked_enemies = vec![0, 7, 2];  // 7は無効な添字
+        game.fire_lock_on_lasers();
+        
+        assert_eq!(game.lock_on_lasers.len(), 2);
+        assert_eq!(game.stats.volleys_by_size[1], 1);
+        assert!(matches!(game.events[..], [GameEvent::LocksFired(2)]));
+        
+        // 1本も発射できなかった場合は記録・通知しない
+        game.events.clear();
+        game.lock_system.locked_enemies = vec![9];
+        game.fire_lock_on_lasers();
+        assert_eq!(game.stats.volleys_by_size.iter().sum::<u32>(), 1);
+        assert!(game.events.is_empty());
+    }
+    
+    #[test]
+    fn update_homing_laser_targets_follows_swap_removed_enemies() {
+        let mut game = game_with_enemies(5);
+        for target in [Some(0), Some(1), Some(2), Some(4), None] {
+            game.lock_on_lasers.spawn(homing_laser(target));
+        }
+        
+        game.update_homing_laser_targets(&[1, 3]);
+        let targets: Vec<_> = game.lock_on_lasers.iter().map(|laser| laser.target_enemy_id).collect();
+        assert_eq!(targets, vec![Some(0), None, Some(2), Some(1), None]);
+    }
+    
+    #[test]
+    fn fan_trajectories_spread_from_left_to_right_and_depend_on_the_seed() {
+        let start = Vec2::new(400.0, 500.0);
+        let target = Vec2::new(400.0, 200.0);
+        let fan = |seed: u64| {
+            let game = Game::new();
+            game.trajectory_rng.srand(seed);
+            [0, 1, 2].map(|lock_idx| game.fan_trajectory(start, target, lock_idx, 3))
+        };
+        
+        let [left, middle, right] = fan(1);
+        assert!(left.launch_offset.x < middle.launch_offset.x && middle.launch_offset.x < right.launch_offset.x);
+        assert!([left, middle, right].iter().all(|trajectory| trajectory.launch_offset.y < 0.0));
+        
+        // 同じシードでは同じ軌道、異なるシードでは異なる軌道
+        assert_eq!(fan(1), [left, middle, right]);
+        assert_ne!(fan(2), [left, middle, right]);
+    }
+}
@@ -1,24 +1,38 @@
-// WebAssembly用のエントリポイント
-#[cfg(feature = "web")]
-use wasm_bindgen::prelude::*;
-
-// ゲームロジックモジュール
-mod game;
-
-// WebAssembly用のエントリポイント
-#[cfg(feature = "web")]
-#[wasm_bindgen(start)]
-pub fn main_web() {
-    // WebAssembly環境でのゲーム開始
-    std::panic::set_hook(Box::new(console_error_panic_hook::hook));
-    console_log::init_with_level(log::Level::Debug).expect("error initializing log");
-    
-    log::info!("Terra Lock WebAssembly module initialized");
-}
-
-// WebAssembly用のコンソールログ設定
-#[cfg(feature = "web")]
-extern crate console_error_panic_hook;
-
-#[cfg(feature = "web")]
-extern crate console_log;
+// ライブラリエントリポイント（WebAssembly・ネイティブ・ツール共通）
+
+// ゲームロジックモジュール（input, lockon, weapons, enemies, spawning, scoring, render, ui）
+pub mod game;
+
+// プレイ統計
+pub mod stats;
+
+// ボット（自動操作）インターフェース
+pub mod bot;
+
+// 空間分割（当たり判定・ロックオン判定の高速化）
+pub mod spatial;
+
+// 当たり判定（形状・掃引判定）
+pub mod collision;
+
+// オブジェクトプール（固定容量・フリーリスト）
+pub mod pool;
+
+// 固定タイムステップ
+pub mod timestep;
+
+// リプレイ（入力記録・回帰テスト）
+pub mod replay;
+
+// 描画バックエンド（macroquad・ヌル・スナップショット）
+pub mod renderer;
+
+// ソフトウェアラスタライザ（見た目のスナップショットテスト用）
+#[cfg(not(target_arch = "wasm32"))]
+pub mod raster;
+
+// ホストページ連携API
+mod web_api;
+
+// ツール・ボットから使う主要な型
+pub use game::{EnemyType, Game, GameConfig, GameEvent, GameState, InputFrame};
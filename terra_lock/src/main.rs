@@ -1,7 +1,5 @@
 // ネイティブ環境用のエントリポイント
-mod game;
-
 #[macroquad::main("Terra Lock")]
 async fn main() {
-    game::main().await;
+    terra_lock::game::main().await;
 }
@@ -0,0 +1,243 @@
+// オブジェクトプール（固定容量）
+//
+// 確保済みの領域を使い回し、定常状態ではヒープ確保を行わない。
+// 有効な要素は先頭に詰めて格納し、削除は末尾要素との入れ替え（swap-remove）で行う。
+// 末尾の無効領域がフリーリストとなり、次回の生成で再利用される。
+// 削除で要素の並び順が変わるため、添字で要素を参照する側は remap_after_swap_remove で調整すること。
+
+use std::ops::{Deref, DerefMut};
+
+#[derive(Clone, Debug)]
+pub struct Pool<T> {
+    items: Vec<T>,      // 有効な要素 [0, active) とフリーリスト [active, len)
+    active: usize,
+    capacity: usize,
+    peak: usize,        // 最大同時使用数
+    overflow: u32,      // 容量不足で生成できなかった回数
+}
+
+// デバッグ表示用の使用状況
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PoolMetrics {
+    pub active: usize,
+    pub free: usize,
+    pub capacity: usize,
+    pub peak: usize,
+    pub overflow: u32,
+}
+
+impl<T> Pool<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            items: Vec::with_capacity(capacity),
+            active: 0,
+            capacity,
+            peak: 0,
+            overflow: 0,
+        }
+    }
+
+    // 要素を追加して添字を返す（容量不足の場合はNone）
+    pub fn spawn(&mut self, item: T) -> Option<usize> {
+        if self.active < self.items.len() {
+            // フリーリストの先頭を再利用
+            self.items[self.active] = item;
+        } else if self.items.len() < self.capacity {
+            self.items.push(item);
+        } else {
+            self.overflow += 1;
+            return None;
+        }
+        Some(self.activate())
+    }
+
+    // フリーリストの要素をそのまま初期化して追加（要素内のバッファも再利用される）
+    pub fn spawn_with(&mut self, init: impl FnOnce(&mut T)) -> Option<usize>
+    where
+        T: Default,
+    {
+        if self.active == self.items.len() {
+            if self.items.len() == self.capacity {
+                self.overflow += 1;
+                return None;
+            }
+            self.items.push(T::default());
+        }
+        init(&mut self.items[self.active]);
+        Some(self.activate())
+    }
+
+    fn activate(&mut self) -> usize {
+        let index = self.active;
+        self.active += 1;
+        self.peak = self.peak.max(self.active);
+        index
+    }
+
+    // 要素を削除し、末尾の有効要素を空いた位置へ移動する
+    pub fn swap_remove(&mut self, index: usize) {
+        assert!(index < self.active, "pool index out of range");
+        self.active -= 1;
+        self.items.swap(index, self.active);
+    }
+
+    // 条件を満たさない要素を削除（並び順は保持されない）
+    pub fn retain(&mut self, mut keep: impl FnMut(&T) -> bool) {
+        let mut index = 0;
+        while index < self.active {
+            if keep(&self.items[index]) {
+                index += 1;
+            } else {
+                self.swap_remove(index);
+            }
+        }
+    }
+
+    // 全要素をフリーリストへ戻す（使用状況の統計は保持）
+    pub fn clear(&mut self) {
+        self.active = 0;
+    }
+
+    pub fn metrics(&self) -> PoolMetrics {
+        PoolMetrics {
+            active: self.active,
+            free: self.capacity - self.active,
+            capacity: self.capacity,
+            peak: self.peak,
+            overflow: self.overflow,
+        }
+    }
+}
+
+// 有効な要素はスライスとして参照できる（len, iter, 添字アクセス等）
+impl<T> Deref for Pool<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items[..self.active]
+    }
+}
+
+impl<T> DerefMut for Pool<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.items[..self.active]
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Pool<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut Pool<T> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+// swap_remove 後の添字の調整
+//
+// removed は昇順・重複なしで、大きい添字から順に削除された前提（削除前の要素数は len）。
+// 削除された要素を指していた場合はNone、末尾から移動した要素は移動先の添字を返す。
+pub fn remap_after_swap_remove(index: usize, removed: &[usize], len: usize) -> Option<usize> {
+    let mut index = index;
+    let mut last = len;
+    for &removed_index in removed.iter().rev() {
+        last -= 1;
+        if index == removed_index {
+            return None;
+        }
+        if index == last {
+            index = removed_index;
+        }
+    }
+    Some(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spawn_stops_at_capacity_and_counts_overflow() {
+        let mut pool = Pool::with_capacity(2);
+        assert_eq!(pool.spawn(1), Some(0));
+        assert_eq!(pool.spawn(2), Some(1));
+        assert_eq!(pool.spawn(3), None);
+
+        let metrics = pool.metrics();
+        assert_eq!((metrics.active, metrics.free, metrics.peak, metrics.overflow), (2, 0, 2, 1));
+    }
+
+    #[test]
+    fn swap_remove_moves_last_item_and_reuses_freed_slot() {
+        let mut pool = Pool::with_capacity(4);
+        for value in [10, 11, 12, 13] {
+            pool.spawn(value);
+        }
+
+        pool.swap_remove(1);
+        assert_eq!(&pool[..], &[10, 13, 12]);
+
+        assert_eq!(pool.spawn(14), Some(3));
+        assert_eq!(&pool[..], &[10, 13, 12, 14]);
+        assert_eq!(pool.metrics().overflow, 0);
+    }
+
+    #[test]
+    fn spawn_with_reuses_buffers_of_freed_items() {
+        let mut pool: Pool<String> = Pool::with_capacity(1);
+        pool.spawn_with(|text| text.push_str("BONUS +300"));
+        let capacity = pool[0].capacity();
+        pool.clear();
+
+        pool.spawn_with(|text| {
+            text.clear();
+            text.push_str("BONUS +600");
+        });
+        assert_eq!(pool[0], "BONUS +600");
+        assert_eq!(pool[0].capacity(), capacity);
+    }
+
+    #[test]
+    fn retain_removes_all_rejected_items() {
+        let mut pool = Pool::with_capacity(8);
+        for value in 0..8 {
+            pool.spawn(value);
+        }
+        pool.retain(|value| value % 3 != 0);
+
+        let mut values = pool.to_vec();
+        values.sort_unstable();
+        assert_eq!(values, vec![1, 2, 4, 5, 7]);
+    }
+
+    #[test]
+    fn remap_after_swap_remove_matches_actual_removal() {
+        let len = 7;
+        let removed = [0, 2, 5, 6];
+
+        let mut pool = Pool::with_capacity(len);
+        for value in 0..len {
+            pool.spawn(value);
+        }
+        for &index in removed.iter().rev() {
+            pool.swap_remove(index);
+        }
+
+        // 各要素の調整後の添字が実際の移動先と一致すること
+        for original in 0..len {
+            match remap_after_swap_remove(original, &removed, len) {
+                Some(index) => assert_eq!(pool[index], original),
+                None => assert!(removed.contains(&original)),
+            }
+        }
+    }
+}
@@ -0,0 +1,326 @@
+// ソフトウェアラスタライザ
+//
+// GPUやウィンドウのない環境で描画命令を画像に変換する（見た目のスナップショットテスト用）。
+// 形状・文字の配置は macroquad の描画に合わせているが、アンチエイリアスや円の多角形近似など
+// 細部は一致しないため、比較は許容誤差付きで行う。
+//   - 各ピクセルの中心で内外を判定し、アルファ合成する
+//   - 文字は macroquad 同梱の ProggyClean を fontdue でラスタライズし、同じ位置に配置する
+
+use std::collections::HashMap;
+
+use fontdue::{Font, FontSettings, Metrics};
+use macroquad::prelude::{Color, Vec2};
+
+use crate::renderer::Renderer;
+
+// macroquad の既定フォント（ProggyClean、MITライセンス）
+const DEFAULT_FONT: &[u8] = include_bytes!("../assets/ProggyClean.ttf");
+
+// RGBA 8bit の画像
+#[derive(Clone, Debug, PartialEq)]
+pub struct RasterImage {
+    pub width: u32,
+    pub height: u32,
+    pub pixels: Vec<u8>,  // 行優先、1ピクセル4バイト
+}
+
+// 画像比較の結果
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ImageDiff {
+    pub differing_pixels: usize,  // いずれかのチャンネル差が許容値を超えたピクセル数
+    pub max_delta: u8,            // チャンネル差の最大値
+}
+
+impl RasterImage {
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; (width * height * 4) as usize],
+        }
+    }
+
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let offset = ((y * self.width + x) * 4) as usize;
+        [self.pixels[offset], self.pixels[offset + 1], self.pixels[offset + 2], self.pixels[offset + 3]]
+    }
+
+    pub fn to_png(&self) -> Result<Vec<u8>, String> {
+        let mut data = Vec::new();
+        let mut encoder = png::Encoder::new(&mut data, self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().map_err(|error| error.to_string())?;
+        writer.write_image_data(&self.pixels).map_err(|error| error.to_string())?;
+        writer.finish().map_err(|error| error.to_string())?;
+        Ok(data)
+    }
+
+    pub fn from_png(data: &[u8]) -> Result<Self, String> {
+        let mut reader = png::Decoder::new(data).read_info().map_err(|error| error.to_string())?;
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut pixels).map_err(|error| error.to_string())?;
+        if info.color_type != png::ColorType::Rgba || info.bit_depth != png::BitDepth::Eight {
+            return Err(format!("unsupported PNG format: {:?} {:?}", info.color_type, info.bit_depth));
+        }
+        pixels.truncate(info.buffer_size());
+        Ok(Self {
+            width: info.width,
+            height: info.height,
+            pixels,
+        })
+    }
+
+    // チャンネルごとの差が tolerance を超えるピクセルを数える（サイズが異なる場合は全ピクセルが不一致）
+    pub fn compare(&self, other: &RasterImage, tolerance: u8) -> ImageDiff {
+        if self.width != other.width || self.height != other.height {
+            return ImageDiff {
+                differing_pixels: (self.width * self.height).max(other.width * other.height) as usize,
+                max_delta: u8::MAX,
+            };
+        }
+
+        let mut diff = ImageDiff::default();
+        for (a, b) in self.pixels.chunks_exact(4).zip(other.pixels.chunks_exact(4)) {
+            let delta = a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).max().unwrap_or(0);
+            diff.max_delta = diff.max_delta.max(delta);
+            if delta > tolerance {
+                diff.differing_pixels += 1;
+            }
+        }
+        diff
+    }
+
+    // 不一致箇所を赤、一致箇所を暗く表示した差分画像（失敗時の確認用）
+    pub fn diff_image(&self, other: &RasterImage, tolerance: u8) -> RasterImage {
+        let mut image = RasterImage::new(self.width, self.height);
+        if self.width != other.width || self.height != other.height {
+            return image;
+        }
+
+        for (i, (a, b)) in self.pixels.chunks_exact(4).zip(other.pixels.chunks_exact(4)).enumerate() {
+            let delta = a.iter().zip(b).map(|(a, b)| a.abs_diff(*b)).max().unwrap_or(0);
+            let pixel = if delta > tolerance {
+                [255, 0, 0, 255]
+            } else {
+                let luma = ((a[0] as u32 + a[1] as u32 + a[2] as u32) / 12) as u8;
+                [luma, luma, luma, 255]
+            };
+            image.pixels[i * 4..i * 4 + 4].copy_from_slice(&pixel);
+        }
+        image
+    }
+}
+
+// 描画命令を RasterImage に描き込むバックエンド
+pub struct RasterRenderer {
+    pub image: RasterImage,
+    font: Font,
+    glyphs: HashMap<(char, u16), (Metrics, Vec<u8>)>,  // ラスタライズ済みグリフ（文字, ピクセルサイズ）
+}
+
+impl RasterRenderer {
+    pub fn new(width: u32, height: u32) -> Self {
+        let font = Font::from_bytes(DEFAULT_FONT, FontSettings::default()).expect("bundled font");
+        Self {
+            image: RasterImage::new(width, height),
+            font,
+            glyphs: HashMap::new(),
+        }
+    }
+
+    // ピクセル (x, y) に色を合成（source-over）
+    fn blend(&mut self, x: i32, y: i32, color: Color, coverage: f32) {
+        if x < 0 || y < 0 || x >= self.image.width as i32 || y >= self.image.height as i32 {
+            return;
+        }
+
+        let alpha = (color.a * coverage).clamp(0.0, 1.0);
+        if alpha <= 0.0 {
+            return;
+        }
+
+        let offset = ((y as u32 * self.image.width + x as u32) * 4) as usize;
+        let pixel = &mut self.image.pixels[offset..offset + 4];
+        for (channel, source) in pixel.iter_mut().zip([color.r, color.g, color.b]) {
+            let destination = *channel as f32 / 255.0;
+            let blended = source.clamp(0.0, 1.0) * alpha + destination * (1.0 - alpha);
+            *channel = (blended * 255.0).round() as u8;
+        }
+        let destination_alpha = pixel[3] as f32 / 255.0;
+        pixel[3] = ((alpha + destination_alpha * (1.0 - alpha)) * 255.0).round() as u8;
+    }
+
+    // 外接矩形内のピクセル中心で inside を評価して塗りつぶす
+    fn fill(&mut self, min: Vec2, max: Vec2, color: Color, inside: impl Fn(Vec2) -> bool) {
+        let x0 = min.x.floor().max(0.0) as i32;
+        let y0 = min.y.floor().max(0.0) as i32;
+        let x1 = (max.x.ceil() as i32).min(self.image.width as i32);
+        let y1 = (max.y.ceil() as i32).min(self.image.height as i32);
+
+        for y in y0..y1 {
+            for x in x0..x1 {
+                if inside(Vec2::new(x as f32 + 0.5, y as f32 + 0.5)) {
+                    self.blend(x, y, color, 1.0);
+                }
+            }
+        }
+    }
+
+    fn glyph(&mut self, character: char, size: u16) -> &(Metrics, Vec<u8>) {
+        let font = &self.font;
+        self.glyphs
+            .entry((character, size))
+            .or_insert_with(|| font.rasterize(character, size as f32))
+    }
+}
+
+impl Renderer for RasterRenderer {
+    fn clear_background(&mut self, color: Color) {
+        let pixel = [color.r, color.g, color.b, color.a].map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8);
+        for chunk in self.image.pixels.chunks_exact_mut(4) {
+            chunk.copy_from_slice(&pixel);
+        }
+    }
+
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.fill(Vec2::new(x, y), Vec2::new(x + w, y + h), color, |_| true);
+    }
+
+    // 矩形の内側に太さの半分の幅で描画（macroquadと同じ）
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color) {
+        let band = thickness / 2.0;
+        self.draw_rectangle(x, y, w, band, color);
+        self.draw_rectangle(x, y + h - band, w, band, color);
+        self.draw_rectangle(x, y + band, band, h - thickness, color);
+        self.draw_rectangle(x + w - band, y + band, band, h - thickness, color);
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        let center = Vec2::new(x, y);
+        let extent = Vec2::splat(radius);
+        self.fill(center - extent, center + extent, color, |point| point.distance_squared(center) <= radius * radius);
+    }
+
+    // 端点に丸みのない太さ thickness の帯（macroquadと同じ）
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        let start = Vec2::new(x1, y1);
+        let end = Vec2::new(x2, y2);
+        let length = start.distance(end);
+        if length <= f32::EPSILON {
+            return;
+        }
+
+        let direction = (end - start) / length;
+        let half_width = thickness / 2.0;
+        let extent = Vec2::splat(half_width);
+        self.fill(start.min(end) - extent, start.max(end) + extent, color, |point| {
+            let offset = point - start;
+            let along = offset.dot(direction);
+            let across = offset.perp_dot(direction).abs();
+            (0.0..=length).contains(&along) && across <= half_width
+        });
+    }
+
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        let area = (v2 - v1).perp_dot(v3 - v1);
+        if area.abs() <= f32::EPSILON {
+            return;
+        }
+
+        // 頂点の並び順によらず判定できるよう、面積の符号で向きを揃える
+        let sign = area.signum();
+        self.fill(v1.min(v2).min(v3), v1.max(v2).max(v3), color, |point| {
+            [(v1, v2), (v2, v3), (v3, v1)]
+                .iter()
+                .all(|&(a, b)| (b - a).perp_dot(point - a) * sign >= 0.0)
+        });
+    }
+
+    // macroquad の draw_text と同じグリフ配置（ベースライン基準、カーニングあり）
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color) {
+        let size = font_size as u16;
+        let mut pen_x = 0.0;
+        let mut previous: Option<char> = None;
+
+        for character in text.chars() {
+            if let Some(previous) = previous {
+                pen_x += self.font.horizontal_kern(previous, character, size as f32).unwrap_or(0.0);
+            }
+            previous = Some(character);
+
+            let (metrics, bitmap) = self.glyph(character, size).clone();
+            let left = (x + metrics.xmin as f32 + pen_x).round() as i32;
+            let top = (y - metrics.height as f32 - metrics.ymin as f32).round() as i32;
+            for (row, line) in bitmap.chunks_exact(metrics.width.max(1)).enumerate() {
+                for (column, &coverage) in line.iter().enumerate() {
+                    if coverage > 0 {
+                        self.blend(left + column as i32, top + row as i32, color, coverage as f32 / 255.0);
+                    }
+                }
+            }
+            pen_x += metrics.advance_width;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use macroquad::prelude::{BLACK, RED, WHITE};
+
+    #[test]
+    fn shapes_cover_pixel_centers_inside_them() {
+        let mut renderer = RasterRenderer::new(40, 40);
+        renderer.clear_background(BLACK);
+        renderer.draw_rectangle(2.0, 2.0, 4.0, 4.0, WHITE);
+        renderer.draw_rectangle_lines(10.0, 10.0, 20.0, 20.0, 4.0, RED);
+
+        let image = &renderer.image;
+        assert_eq!(image.pixel(2, 2), [255, 255, 255, 255]);
+        assert_eq!(image.pixel(5, 5), [255, 255, 255, 255]);
+        assert_eq!(image.pixel(6, 6), [0, 0, 0, 255]);
+        // 枠線は内側に太さの半分（2px）
+        assert_eq!(image.pixel(11, 20), [230, 41, 56, 255]);
+        assert_eq!(image.pixel(12, 20), [0, 0, 0, 255]);
+        assert_eq!(image.pixel(9, 20), [0, 0, 0, 255]);
+    }
+
+    #[test]
+    fn translucent_colors_blend_over_the_background() {
+        let mut renderer = RasterRenderer::new(4, 4);
+        renderer.clear_background(BLACK);
+        renderer.draw_circle(2.0, 2.0, 2.0, Color::new(1.0, 1.0, 1.0, 0.5));
+
+        assert_eq!(renderer.image.pixel(1, 1), [128, 128, 128, 255]);
+    }
+
+    #[test]
+    fn text_is_drawn_above_the_baseline() {
+        let mut renderer = RasterRenderer::new(64, 32);
+        renderer.clear_background(BLACK);
+        renderer.draw_text("TERRA", 4.0, 20.0, 16.0, WHITE);
+
+        let lit_rows: Vec<u32> = (0..32)
+            .filter(|&y| (0..64).any(|x| renderer.image.pixel(x, y)[0] > 0))
+            .collect();
+        assert!(!lit_rows.is_empty());
+        assert!(*lit_rows.last().unwrap() < 20);
+    }
+
+    #[test]
+    fn png_round_trip_and_compare() {
+        let mut renderer = RasterRenderer::new(8, 8);
+        renderer.clear_background(BLACK);
+        renderer.draw_line(0.0, 4.0, 8.0, 4.0, 2.0, WHITE);
+
+        let image = RasterImage::from_png(&renderer.image.to_png().unwrap()).unwrap();
+        assert_eq!(image, renderer.image);
+        assert_eq!(image.compare(&renderer.image, 0), ImageDiff::default());
+
+        renderer.draw_rectangle(0.0, 0.0, 1.0, 1.0, Color::new(0.02, 0.0, 0.0, 1.0));
+        let diff = image.compare(&renderer.image, 4);
+        assert_eq!(diff.max_delta, 5);
+        assert_eq!(diff.differing_pixels, 1);
+    }
+}
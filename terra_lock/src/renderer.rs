@@ -0,0 +1,273 @@
+// 描画バックエンド
+//
+// ゲームは描画命令を Renderer に発行し、グラフィックスAPIを直接呼ばない。
+// グラフィックスコンテキストのない環境（テスト・ツール）でも描画処理を実行できる。
+//   MacroquadRenderer  画面への描画（ネイティブ・WebAssembly）
+//   NullRenderer       何も描画しない（テスト・ヘッドレス実行）
+//   SnapshotRenderer   描画命令を記録し、SVGとして出力（見た目の回帰テスト用）
+
+use std::fmt::Write;
+
+use macroquad::prelude::{Color, Vec2};
+
+pub trait Renderer {
+    fn clear_background(&mut self, color: Color);
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color);
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color);
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color);
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color);
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color);
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color);  // (x, y) はベースライン左端
+}
+
+// macroquad による画面描画
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MacroquadRenderer;
+
+impl Renderer for MacroquadRenderer {
+    fn clear_background(&mut self, color: Color) {
+        macroquad::prelude::clear_background(color);
+    }
+
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        macroquad::prelude::draw_rectangle(x, y, w, h, color);
+    }
+
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color) {
+        macroquad::prelude::draw_rectangle_lines(x, y, w, h, thickness, color);
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        macroquad::prelude::draw_circle(x, y, radius, color);
+    }
+
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        macroquad::prelude::draw_line(x1, y1, x2, y2, thickness, color);
+    }
+
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        macroquad::prelude::draw_triangle(v1, v2, v3, color);
+    }
+
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color) {
+        macroquad::prelude::draw_text(text, x, y, font_size, color);
+    }
+}
+
+// 何も描画しない（描画命令の数のみ数える）
+#[derive(Clone, Copy, Debug, Default)]
+pub struct NullRenderer {
+    pub draw_calls: usize,
+}
+
+impl Renderer for NullRenderer {
+    fn clear_background(&mut self, _color: Color) {
+        self.draw_calls += 1;
+    }
+
+    fn draw_rectangle(&mut self, _x: f32, _y: f32, _w: f32, _h: f32, _color: Color) {
+        self.draw_calls += 1;
+    }
+
+    fn draw_rectangle_lines(&mut self, _x: f32, _y: f32, _w: f32, _h: f32, _thickness: f32, _color: Color) {
+        self.draw_calls += 1;
+    }
+
+    fn draw_circle(&mut self, _x: f32, _y: f32, _radius: f32, _color: Color) {
+        self.draw_calls += 1;
+    }
+
+    fn draw_line(&mut self, _x1: f32, _y1: f32, _x2: f32, _y2: f32, _thickness: f32, _color: Color) {
+        self.draw_calls += 1;
+    }
+
+    fn draw_triangle(&mut self, _v1: Vec2, _v2: Vec2, _v3: Vec2, _color: Color) {
+        self.draw_calls += 1;
+    }
+
+    fn draw_text(&mut self, _text: &str, _x: f32, _y: f32, _font_size: f32, _color: Color) {
+        self.draw_calls += 1;
+    }
+}
+
+// 記録された描画命令
+#[derive(Clone, Debug, PartialEq)]
+pub enum DrawCommand {
+    Clear { color: Color },
+    Rectangle { x: f32, y: f32, w: f32, h: f32, color: Color },
+    RectangleLines { x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color },
+    Circle { x: f32, y: f32, radius: f32, color: Color },
+    Line { x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color },
+    Triangle { v1: Vec2, v2: Vec2, v3: Vec2, color: Color },
+    Text { text: String, x: f32, y: f32, font_size: f32, color: Color },
+}
+
+// 描画命令を記録するスナップショット用バックエンド
+#[derive(Clone, Debug)]
+pub struct SnapshotRenderer {
+    pub width: f32,
+    pub height: f32,
+    pub commands: Vec<DrawCommand>,
+}
+
+impl SnapshotRenderer {
+    pub fn new(width: f32, height: f32) -> Self {
+        Self {
+            width,
+            height,
+            commands: Vec::new(),
+        }
+    }
+
+    // 描画されたテキストの一覧（描画順）
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.commands.iter().filter_map(|command| match command {
+            DrawCommand::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+    }
+
+    // SVG形式で出力（座標は小数第2位に丸め、同じ描画命令からは同じ文字列になる）
+    pub fn to_svg(&self) -> String {
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+            self.width, self.height, self.width, self.height
+        );
+
+        for command in &self.commands {
+            let _ = match command {
+                DrawCommand::Clear { color } => writeln!(
+                    svg,
+                    r#"<rect width="100%" height="100%" {}/>"#,
+                    svg_paint("fill", *color)
+                ),
+                DrawCommand::Rectangle { x, y, w, h, color } => writeln!(
+                    svg,
+                    r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" {}/>"#,
+                    x, y, w, h, svg_paint("fill", *color)
+                ),
+                // 枠線は矩形の内側に太さの半分の幅で描画（macroquadと同じ）
+                DrawCommand::RectangleLines { x, y, w, h, thickness, color } => writeln!(
+                    svg,
+                    r#"<rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="none" stroke-width="{:.2}" {}/>"#,
+                    x + thickness / 4.0, y + thickness / 4.0, w - thickness / 2.0, h - thickness / 2.0, thickness / 2.0,
+                    svg_paint("stroke", *color)
+                ),
+                DrawCommand::Circle { x, y, radius, color } => writeln!(
+                    svg,
+                    r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" {}/>"#,
+                    x, y, radius, svg_paint("fill", *color)
+                ),
+                DrawCommand::Line { x1, y1, x2, y2, thickness, color } => writeln!(
+                    svg,
+                    r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke-width="{:.2}" {}/>"#,
+                    x1, y1, x2, y2, thickness, svg_paint("stroke", *color)
+                ),
+                DrawCommand::Triangle { v1, v2, v3, color } => writeln!(
+                    svg,
+                    r#"<polygon points="{:.2},{:.2} {:.2},{:.2} {:.2},{:.2}" {}/>"#,
+                    v1.x, v1.y, v2.x, v2.y, v3.x, v3.y, svg_paint("fill", *color)
+                ),
+                DrawCommand::Text { text, x, y, font_size, color } => writeln!(
+                    svg,
+                    r#"<text x="{:.2}" y="{:.2}" font-family="monospace" font-size="{:.2}" {}>{}</text>"#,
+                    x, y, font_size, svg_paint("fill", *color), escape_xml(text)
+                ),
+            };
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+}
+
+impl Renderer for SnapshotRenderer {
+    fn clear_background(&mut self, color: Color) {
+        self.commands.clear();
+        self.commands.push(DrawCommand::Clear { color });
+    }
+
+    fn draw_rectangle(&mut self, x: f32, y: f32, w: f32, h: f32, color: Color) {
+        self.commands.push(DrawCommand::Rectangle { x, y, w, h, color });
+    }
+
+    fn draw_rectangle_lines(&mut self, x: f32, y: f32, w: f32, h: f32, thickness: f32, color: Color) {
+        self.commands.push(DrawCommand::RectangleLines { x, y, w, h, thickness, color });
+    }
+
+    fn draw_circle(&mut self, x: f32, y: f32, radius: f32, color: Color) {
+        self.commands.push(DrawCommand::Circle { x, y, radius, color });
+    }
+
+    fn draw_line(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, thickness: f32, color: Color) {
+        self.commands.push(DrawCommand::Line { x1, y1, x2, y2, thickness, color });
+    }
+
+    fn draw_triangle(&mut self, v1: Vec2, v2: Vec2, v3: Vec2, color: Color) {
+        self.commands.push(DrawCommand::Triangle { v1, v2, v3, color });
+    }
+
+    fn draw_text(&mut self, text: &str, x: f32, y: f32, font_size: f32, color: Color) {
+        self.commands.push(DrawCommand::Text { text: text.to_string(), x, y, font_size, color });
+    }
+}
+
+// SVGの塗り・線の色指定（透明度は別属性）
+fn svg_paint(attribute: &str, color: Color) -> String {
+    let [r, g, b, a] = [color.r, color.g, color.b, color.a].map(|value| (value.clamp(0.0, 1.0) * 255.0).round() as u8);
+    if a == 255 {
+        format!(r##"{}="#{:02x}{:02x}{:02x}""##, attribute, r, g, b)
+    } else {
+        format!(r##"{}="#{:02x}{:02x}{:02x}" {}-opacity="{:.3}""##, attribute, r, g, b, attribute, a as f32 / 255.0)
+    }
+}
+
+fn escape_xml(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use macroquad::prelude::{BLACK, RED, WHITE};
+
+    #[test]
+    fn snapshot_renderer_writes_each_command_as_svg() {
+        let mut renderer = SnapshotRenderer::new(800.0, 600.0);
+        renderer.clear_background(BLACK);
+        renderer.draw_circle(10.0, 20.0, 5.0, RED);
+        renderer.draw_line(0.0, 0.0, 3.0, 4.0, 2.0, Color::new(1.0, 1.0, 1.0, 0.5));
+        renderer.draw_text("A < B & C", 1.0, 2.0, 16.0, WHITE);
+
+        let svg = renderer.to_svg();
+        assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="800" height="600""#));
+        assert!(svg.contains(r##"<rect width="100%" height="100%" fill="#000000"/>"##));
+        assert!(svg.contains(r##"<circle cx="10.00" cy="20.00" r="5.00" fill="#e62938"/>"##));
+        assert!(svg.contains(r##"stroke="#ffffff" stroke-opacity="0.502""##));
+        assert!(svg.contains(">A &lt; B &amp; C</text>"));
+        assert!(svg.ends_with("</svg>\n"));
+    }
+
+    #[test]
+    fn clear_background_starts_a_new_frame() {
+        let mut renderer = SnapshotRenderer::new(800.0, 600.0);
+        renderer.draw_text("old", 0.0, 0.0, 16.0, WHITE);
+        renderer.clear_background(BLACK);
+        renderer.draw_text("new", 0.0, 0.0, 16.0, WHITE);
+
+        assert_eq!(renderer.commands.len(), 2);
+        assert_eq!(renderer.texts().collect::<Vec<_>>(), vec!["new"]);
+    }
+}
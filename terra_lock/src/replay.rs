@@ -0,0 +1,379 @@
+// リプレイ（入力記録の再生と回帰テスト用の期待値）
+//
+// シードと各ステップの入力を記録し、同じ入力で再生して最終スコア・撃破数・状態ハッシュを比較する。
+// 固定タイムステップとGameごとの乱数により、同じリプレイは常に同じ結果になる。
+//
+// ファイル形式（テキスト、行単位）:
+//   version 2
+//   seed <シード>
+//   expect state|steps|score|kills|hash <値>
+//   checkpoint <ステップ数> <状態ハッシュ>
+//   frames
+//   <連続数> <マウスX> <マウスY> <左ボタン 0|1> [<ボム 0|1>]   （ランレングス圧縮、ボム列は押下時のみ）
+
+use macroquad::prelude::Vec2;
+
+use crate::bot::Controller;
+use crate::game::{EnemyType, Game, GameState, InputFrame};
+use crate::timestep::FIXED_TIMESTEP;
+
+// 形式の変更時に更新（2: 撃破数の期待値を全敵機タイプ分に拡張。旧形式のリプレイは再記録が必要）
+pub const REPLAY_VERSION: u32 = 2;
+
+// 状態ハッシュを記録する間隔（10秒ごと）
+pub const CHECKPOINT_INTERVAL: usize = 1200;
+
+// 状態ハッシュ（FNV-1a 64bit、浮動小数点はビット列で比較）
+pub struct StateHasher(u64);
+
+impl Default for StateHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl StateHasher {
+    pub fn new() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+
+    pub fn write_bytes(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    pub fn write_u32(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes());
+    }
+
+    pub fn write_usize(&mut self, value: usize) {
+        self.write_bytes(&(value as u64).to_le_bytes());
+    }
+
+    pub fn write_bool(&mut self, value: bool) {
+        self.write_bytes(&[value as u8]);
+    }
+
+    pub fn write_f32(&mut self, value: f32) {
+        self.write_u32(value.to_bits());
+    }
+
+    pub fn write_vec2(&mut self, value: Vec2) {
+        self.write_f32(value.x);
+        self.write_f32(value.y);
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+// 再生結果（リプレイファイルの期待値）
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReplayOutcome {
+    pub state: GameState,
+    pub steps: usize,
+    pub score: u32,
+    pub kills_by_type: [u32; EnemyType::COUNT],
+    pub state_hash: u64,
+    pub checkpoints: Vec<(usize, u64)>,   // （ステップ数、状態ハッシュ）
+}
+
+impl ReplayOutcome {
+    // 期待値との差分の説明（一致する場合はNone）
+    pub fn describe_mismatch(&self, actual: &ReplayOutcome) -> Option<String> {
+        if self == actual {
+            return None;
+        }
+
+        let mut lines = Vec::new();
+        if self.state != actual.state {
+            lines.push(format!("state: expected {}, got {}", state_name(self.state), state_name(actual.state)));
+        }
+        if self.steps != actual.steps {
+            lines.push(format!("steps: expected {}, got {}", self.steps, actual.steps));
+        }
+        if self.score != actual.score {
+            lines.push(format!("score: expected {}, got {}", self.score, actual.score));
+        }
+        if self.kills_by_type != actual.kills_by_type {
+            lines.push(format!("kills: expected {:?}, got {:?}", self.kills_by_type, actual.kills_by_type));
+        }
+        if self.state_hash != actual.state_hash {
+            lines.push(format!("hash: expected {:016x}, got {:016x}", self.state_hash, actual.state_hash));
+        }
+
+        // 最初に食い違ったチェックポイント（どの時点で結果が変わったか）
+        let diverged = self
+            .checkpoints
+            .iter()
+            .zip(&actual.checkpoints)
+            .find(|(expected, actual)| expected != actual);
+        if let Some(((step, _), _)) = diverged {
+            lines.push(format!(
+                "first diverging checkpoint: step {} ({:.1}s)",
+                step,
+                *step as f32 * FIXED_TIMESTEP
+            ));
+        } else if self.checkpoints.len() != actual.checkpoints.len() {
+            lines.push(format!(
+                "checkpoints: expected {}, got {}",
+                self.checkpoints.len(),
+                actual.checkpoints.len()
+            ));
+        }
+        Some(lines.join("\n"))
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Replay {
+    pub seed: u64,
+    pub frames: Vec<InputFrame>,
+    pub expected: Option<ReplayOutcome>,
+}
+
+impl Replay {
+    // コントローラーの操作を記録（ゲームオーバーまたは max_steps で終了）
+    pub fn record(controller: &mut dyn Controller, seed: u64, max_steps: usize) -> Self {
+        let mut game = Game::new();
+        game.start_game_with_seed(seed);
+        controller.reset();
+
+        let mut frames = Vec::new();
+        while game.state() == GameState::Playing && frames.len() < max_steps {
+            let frame = quantize(controller.next_input(&game.observe(), FIXED_TIMESTEP));
+            game.step(FIXED_TIMESTEP, frame);
+            game.drain_events();
+            frames.push(frame);
+        }
+
+        let mut replay = Self { seed, frames, expected: None };
+        replay.expected = Some(replay.play());
+        replay
+    }
+
+    // 記録した入力で再生
+    pub fn play(&self) -> ReplayOutcome {
+        let mut game = Game::new();
+        game.start_game_with_seed(self.seed);
+
+        let mut checkpoints = Vec::new();
+        for (i, &frame) in self.frames.iter().enumerate() {
+            game.step(FIXED_TIMESTEP, frame);
+            game.drain_events();
+
+            let steps = i + 1;
+            if steps % CHECKPOINT_INTERVAL == 0 {
+                checkpoints.push((steps, game.state_hash()));
+            }
+        }
+
+        ReplayOutcome {
+            state: game.state(),
+            steps: self.frames.len(),
+            score: game.score(),
+            kills_by_type: game.stats().kills_by_type,
+            state_hash: game.state_hash(),
+            checkpoints,
+        }
+    }
+
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut version = None;
+        let mut seed = None;
+        let mut expected = ExpectedFields::default();
+        let mut frames = Vec::new();
+        let mut in_frames = false;
+
+        for (line_number, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let error = |message: &str| format!("line {}: {}", line_number + 1, message);
+            let fields: Vec<&str> = line.split_whitespace().collect();
+
+            if in_frames {
+                let (count, x, y, button, bomb) = match fields[..] {
+                    [count, x, y, button] => (count, x, y, button, "0"),
+                    [count, x, y, button, bomb] => (count, x, y, button, bomb),
+                    _ => return Err(error("expected `<count> <x> <y> <button> [<bomb>]`")),
+                };
+                let count: usize = count.parse().map_err(|_| error("invalid frame count"))?;
+                let mouse_pos = Vec2::new(
+                    x.parse().map_err(|_| error("invalid mouse x"))?,
+                    y.parse().map_err(|_| error("invalid mouse y"))?,
+                );
+                let left_button_down = match button {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(error("button must be 0 or 1")),
+                };
+                let bomb_button_down = match bomb {
+                    "0" => false,
+                    "1" => true,
+                    _ => return Err(error("bomb must be 0 or 1")),
+                };
+                let frame = InputFrame { mouse_pos, left_button_down, bomb_button_down };
+                frames.extend(std::iter::repeat_n(frame, count));
+                continue;
+            }
+
+            match fields[..] {
+                ["version", value] => {
+                    // 以降の行は形式が異なる可能性があるため、非対応のバージョンはその場で拒否
+                    let value = value.parse::<u32>().map_err(|_| error("invalid version"))?;
+                    if value != REPLAY_VERSION {
+                        return Err(error(&format!(
+                            "unsupported replay version {} (expected {}, re-record the replay)",
+                            value, REPLAY_VERSION
+                        )));
+                    }
+                    version = Some(value);
+                }
+                ["seed", value] => seed = Some(value.parse::<u64>().map_err(|_| error("invalid seed"))?),
+                ["expect", key, ref values @ ..] => expected.set(key, values).map_err(|message| error(&message))?,
+                ["checkpoint", step, hash] => expected.checkpoints.push((
+                    step.parse().map_err(|_| error("invalid checkpoint step"))?,
+                    u64::from_str_radix(hash, 16).map_err(|_| error("invalid checkpoint hash"))?,
+                )),
+                ["frames"] => in_frames = true,
+                _ => return Err(error(&format!("unknown line `{}`", line))),
+            }
+        }
+
+        if version.is_none() {
+            return Err("missing version".to_string());
+        }
+
+        Ok(Self {
+            seed: seed.ok_or("missing seed")?,
+            frames,
+            expected: expected.into_outcome(),
+        })
+    }
+
+    pub fn to_text(&self) -> String {
+        let mut lines = vec![
+            "# Terra Lock replay".to_string(),
+            "# 期待値の更新: cargo run --bin replay -- update <file>".to_string(),
+            format!("version {}", REPLAY_VERSION),
+            format!("seed {}", self.seed),
+        ];
+
+        if let Some(expected) = &self.expected {
+            let kills: Vec<String> = expected.kills_by_type.iter().map(|kills| kills.to_string()).collect();
+            lines.push(format!("expect state {}", state_name(expected.state)));
+            lines.push(format!("expect steps {}", expected.steps));
+            lines.push(format!("expect score {}", expected.score));
+            lines.push(format!("expect kills {}", kills.join(" ")));
+            lines.push(format!("expect hash {:016x}", expected.state_hash));
+            for (step, hash) in &expected.checkpoints {
+                lines.push(format!("checkpoint {} {:016x}", step, hash));
+            }
+        }
+
+        // 同じ入力が続く区間はまとめて記録
+        lines.push("frames".to_string());
+        let mut i = 0;
+        while i < self.frames.len() {
+            let frame = self.frames[i];
+            let count = self.frames[i..].iter().take_while(|&&other| other == frame).count();
+            let mut line = format!(
+                "{} {} {} {}",
+                count,
+                frame.mouse_pos.x,
+                frame.mouse_pos.y,
+                frame.left_button_down as u8
+            );
+            // ボム列は押下中のみ付加（既存のリプレイと同じ形式を保つ）
+            if frame.bomb_button_down {
+                line.push_str(" 1");
+            }
+            lines.push(line);
+            i += count;
+        }
+
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
+// テキストで正確に保存できるよう、マウス座標を整数に丸める
+fn quantize(frame: InputFrame) -> InputFrame {
+    InputFrame {
+        mouse_pos: frame.mouse_pos.round(),
+        left_button_down: frame.left_button_down,
+        bomb_button_down: frame.bomb_button_down,
+    }
+}
+
+fn state_name(state: GameState) -> &'static str {
+    match state {
+        GameState::Title => "title",
+        GameState::Playing => "playing",
+        GameState::GameOver => "gameover",
+        GameState::Demo => "demo",
+        GameState::ShipSelect => "shipselect",
+    }
+}
+
+// 読み込み中の期待値（全項目が揃った場合のみ有効）
+#[derive(Default)]
+struct ExpectedFields {
+    state: Option<GameState>,
+    steps: Option<usize>,
+    score: Option<u32>,
+    kills_by_type: Option<[u32; EnemyType::COUNT]>,
+    state_hash: Option<u64>,
+    checkpoints: Vec<(usize, u64)>,
+}
+
+impl ExpectedFields {
+    fn set(&mut self, key: &str, values: &[&str]) -> Result<(), String> {
+        let single = || match values {
+            [value] => Ok(*value),
+            _ => Err(format!("expect {} takes one value", key)),
+        };
+
+        match key {
+            "state" => {
+                let name = single()?;
+                let state = [GameState::Title, GameState::Playing, GameState::GameOver, GameState::Demo, GameState::ShipSelect]
+                    .into_iter()
+                    .find(|&state| state_name(state) == name)
+                    .ok_or(format!("unknown state `{}`", name))?;
+                self.state = Some(state);
+            }
+            "steps" => self.steps = Some(single()?.parse().map_err(|_| "invalid steps")?),
+            "score" => self.score = Some(single()?.parse().map_err(|_| "invalid score")?),
+            "kills" => {
+                if values.len() != EnemyType::COUNT {
+                    return Err(format!("expect kills takes {} values", EnemyType::COUNT));
+                }
+                let mut kills = [0; EnemyType::COUNT];
+                for (kill, value) in kills.iter_mut().zip(values) {
+                    *kill = value.parse().map_err(|_| "invalid kill count")?;
+                }
+                self.kills_by_type = Some(kills);
+            }
+            "hash" => self.state_hash = Some(u64::from_str_radix(single()?, 16).map_err(|_| "invalid hash")?),
+            _ => return Err(format!("unknown expectation `{}`", key)),
+        }
+        Ok(())
+    }
+
+    fn into_outcome(self) -> Option<ReplayOutcome> {
+        Some(ReplayOutcome {
+            state: self.state?,
+            steps: self.steps?,
+            score: self.score?,
+            kills_by_type: self.kills_by_type?,
+            state_hash: self.state_hash?,
+            checkpoints: self.checkpoints,
+        })
+    }
+}
@@ -0,0 +1,167 @@
+// 空間分割（一様グリッド）
+//
+// 毎フレーム再構築し、当たり判定・ロックオン判定の候補を絞り込む。
+// 要素は中心座標のセルにのみ登録し、検索側で要素の半径を含めた範囲を指定する。
+// セルごとの要素は連結リストで保持し、再構築時にヒープ確保を行わない。
+
+use macroquad::prelude::Vec2;
+
+// 連結リストの終端
+const NONE: usize = usize::MAX;
+
+#[derive(Clone, Debug)]
+pub struct SpatialGrid {
+    origin: Vec2,        // グリッド左上の座標
+    cell_size: f32,
+    columns: usize,
+    rows: usize,
+    heads: Vec<usize>,           // セルごとの先頭エントリ
+    entries: Vec<(usize, usize)>, // （要素の添字、同じセルの次のエントリ）
+}
+
+impl SpatialGrid {
+    // 領域外の要素は端のセルに登録される（検索も同様に端へ丸める）
+    // capacity は想定する最大要素数（超過時のみ追加確保）
+    pub fn new(origin: Vec2, width: f32, height: f32, cell_size: f32, capacity: usize) -> Self {
+        let columns = (width / cell_size).ceil().max(1.0) as usize;
+        let rows = (height / cell_size).ceil().max(1.0) as usize;
+
+        Self {
+            origin,
+            cell_size,
+            columns,
+            rows,
+            heads: vec![NONE; columns * rows],
+            entries: Vec::with_capacity(capacity),
+        }
+    }
+
+    // 全セルを空にする（確保済みの容量は再利用）
+    pub fn clear(&mut self) {
+        self.heads.fill(NONE);
+        self.entries.clear();
+    }
+
+    pub fn insert(&mut self, index: usize, position: Vec2) {
+        let (column, row) = self.cell_coords(position);
+        let cell = row * self.columns + column;
+        self.entries.push((index, self.heads[cell]));
+        self.heads[cell] = self.entries.len() - 1;
+    }
+
+    // 円と重なるセルの要素を out に追加（候補のみ、順序不定、厳密な判定は呼び出し側で行う）
+    pub fn query_circle(&self, center: Vec2, radius: f32, out: &mut Vec<usize>) {
+        self.query_rect(center - Vec2::splat(radius), center + Vec2::splat(radius), out);
+    }
+
+    // 矩形（min〜max）と重なるセルの要素を out に追加（query_circle と同様に候補のみ）
+    pub fn query_rect(&self, min: Vec2, max: Vec2, out: &mut Vec<usize>) {
+        let (min_column, min_row) = self.cell_coords(min);
+        let (max_column, max_row) = self.cell_coords(max);
+
+        for row in min_row..=max_row {
+            for column in min_column..=max_column {
+                let mut entry = self.heads[row * self.columns + column];
+                while entry != NONE {
+                    let (index, next) = self.entries[entry];
+                    out.push(index);
+                    entry = next;
+                }
+            }
+        }
+    }
+
+    fn cell_coords(&self, position: Vec2) -> (usize, usize) {
+        let local = (position - self.origin) / self.cell_size;
+        let column = (local.x.floor().max(0.0) as usize).min(self.columns - 1);
+        let row = (local.y.floor().max(0.0) as usize).min(self.rows - 1);
+        (column, row)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 200x200の領域を50pxのセルに分割（4x4セル）
+    fn grid() -> SpatialGrid {
+        SpatialGrid::new(Vec2::ZERO, 200.0, 200.0, 50.0, 8)
+    }
+
+    fn query_circle(grid: &SpatialGrid, center: Vec2, radius: f32) -> Vec<usize> {
+        let mut out = Vec::new();
+        grid.query_circle(center, radius, &mut out);
+        out.sort_unstable();
+        out
+    }
+
+    #[test]
+    fn query_returns_items_in_the_same_and_neighbouring_cells() {
+        let mut grid = grid();
+        grid.insert(0, Vec2::new(10.0, 10.0));
+        grid.insert(1, Vec2::new(40.0, 40.0));   // 0と同じセル
+        grid.insert(2, Vec2::new(60.0, 10.0));   // 右隣のセル
+        grid.insert(3, Vec2::new(160.0, 160.0)); // 離れたセル
+
+        assert_eq!(query_circle(&grid, Vec2::new(20.0, 20.0), 5.0), vec![0, 1]);
+        assert_eq!(query_circle(&grid, Vec2::new(45.0, 20.0), 10.0), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn circle_across_cell_borders_covers_every_overlapped_cell() {
+        let mut grid = grid();
+        for (index, position) in [(48.0, 48.0), (52.0, 48.0), (48.0, 52.0), (52.0, 52.0), (120.0, 52.0)]
+            .into_iter()
+            .enumerate()
+        {
+            grid.insert(index, Vec2::new(position.0, position.1));
+        }
+
+        // セルの角をまたぐ円は4セルすべてを検索し、範囲外のセルは含まない
+        assert_eq!(query_circle(&grid, Vec2::new(50.0, 50.0), 1.0), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn positions_outside_the_area_are_clamped_to_edge_cells() {
+        let mut grid = grid();
+        grid.insert(0, Vec2::new(-30.0, 100.0));  // 左端の外
+        grid.insert(1, Vec2::new(500.0, 500.0));  // 右下の外
+        grid.insert(2, Vec2::new(0.0, 0.0));      // 左上の角ちょうど
+        grid.insert(3, Vec2::new(200.0, 200.0));  // 右下の角ちょうど
+
+        assert_eq!(query_circle(&grid, Vec2::new(0.0, 100.0), 5.0), vec![0]);
+        assert_eq!(query_circle(&grid, Vec2::new(300.0, 300.0), 1.0), vec![1, 3]);
+        assert_eq!(query_circle(&grid, Vec2::new(-100.0, -100.0), 1.0), vec![2]);
+
+        // 領域外の矩形も端のセルに丸めて検索
+        let mut out = Vec::new();
+        grid.query_rect(Vec2::new(-100.0, 60.0), Vec2::new(-90.0, 140.0), &mut out);
+        assert_eq!(out, vec![0]);
+    }
+
+    #[test]
+    fn query_rect_covers_the_cells_between_its_corners() {
+        let mut grid = grid();
+        grid.insert(0, Vec2::new(10.0, 110.0));
+        grid.insert(1, Vec2::new(190.0, 120.0));
+        grid.insert(2, Vec2::new(100.0, 10.0));
+
+        let mut out = Vec::new();
+        grid.query_rect(Vec2::new(0.0, 100.0), Vec2::new(200.0, 140.0), &mut out);
+        out.sort_unstable();
+        assert_eq!(out, vec![0, 1]);
+    }
+
+    #[test]
+    fn clear_empties_cells_and_allows_reinsertion() {
+        let mut grid = grid();
+        grid.insert(0, Vec2::new(10.0, 10.0));
+        grid.insert(1, Vec2::new(20.0, 20.0));
+
+        grid.clear();
+        assert!(query_circle(&grid, Vec2::new(15.0, 15.0), 10.0).is_empty());
+
+        grid.insert(5, Vec2::new(15.0, 15.0));
+        assert_eq!(query_circle(&grid, Vec2::new(15.0, 15.0), 10.0), vec![5]);
+    }
+}
@@ -0,0 +1,97 @@
+// プレイ統計（ゲームオーバー画面のサマリー表示・JSON出力用）
+
+use crate::game::EnemyType;
+
+// ロックオン一斉発射の集計区分（1〜6機、6機以上は最後の区分）
+pub const VOLLEY_SIZE_BUCKETS: usize = 6;
+
+#[derive(Clone, Debug, Default)]
+pub struct RunStats {
+    pub ship: &'static str,                            // 使用した自機
+    pub score: u32,
+    pub time_survived: f32,
+    pub difficulty_level: i32,                         // 到達した難易度段階
+    pub kills_by_type: [u32; EnemyType::COUNT],        // 敵機タイプ別撃破数
+    pub normal_shots_fired: u32,
+    pub normal_shots_hit: u32,
+    pub volleys_by_size: [u32; VOLLEY_SIZE_BUCKETS],   // ロックオン数別の一斉発射回数
+    pub bonus_points: u32,                             // 同時撃破ボーナス合計
+    pub bombs_used: u32,                               // 使用したボム数
+    pub peak_enemies: usize,                           // 最大同時敵機数
+    pub death_cause: Option<EnemyType>,                // 被弾した敵機タイプ
+}
+
+impl RunStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record_kill(&mut self, enemy_type: EnemyType) {
+        self.kills_by_type[enemy_type.index()] += 1;
+    }
+
+    pub fn record_volley(&mut self, size: usize) {
+        if size > 0 {
+            self.volleys_by_size[size.min(VOLLEY_SIZE_BUCKETS) - 1] += 1;
+        }
+    }
+
+    pub fn record_enemy_count(&mut self, count: usize) {
+        self.peak_enemies = self.peak_enemies.max(count);
+    }
+
+    pub fn total_kills(&self) -> u32 {
+        self.kills_by_type.iter().sum()
+    }
+
+    // 通常レーザーの命中率（0.0〜1.0、未発射時は0）
+    pub fn accuracy(&self) -> f32 {
+        if self.normal_shots_fired == 0 {
+            0.0
+        } else {
+            self.normal_shots_hit as f32 / self.normal_shots_fired as f32
+        }
+    }
+
+    // バランス調整用のJSON出力
+    pub fn to_json(&self) -> String {
+        let kills = EnemyType::ALL
+            .iter()
+            .map(|enemy_type| {
+                format!("\"{}\":{}", enemy_type.name(), self.kills_by_type[enemy_type.index()])
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+        let volleys = self
+            .volleys_by_size
+            .iter()
+            .map(|count| count.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let death_cause = match self.death_cause {
+            Some(enemy_type) => format!("\"{}\"", enemy_type.name()),
+            None => "null".to_string(),
+        };
+
+        format!(
+            "{{\"ship\":\"{}\",\"score\":{},\"time_survived\":{:.2},\"difficulty_level\":{},\
+             \"kills\":{{{}}},\"normal_shots_fired\":{},\"normal_shots_hit\":{},\
+             \"accuracy\":{:.3},\"volleys_by_size\":[{}],\"bonus_points\":{},\
+             \"bombs_used\":{},\"peak_enemies\":{},\"death_cause\":{}}}",
+            self.ship,
+            self.score,
+            self.time_survived,
+            self.difficulty_level,
+            kills,
+            self.normal_shots_fired,
+            self.normal_shots_hit,
+            self.accuracy(),
+            volleys,
+            self.bonus_points,
+            self.bombs_used,
+            self.peak_enemies,
+            death_cause,
+        )
+    }
+}
@@ -0,0 +1,60 @@
+// 固定タイムステップ
+//
+// 可変のフレーム時間を一定間隔のシミュレーションステップに分割する。
+// フレームレートに依存せず同じ入力から同じ結果が得られ、長時間の停止後もすり抜けが起きない。
+// 描画はステップ間の端数（alpha）で前回と今回の位置を補間する。
+
+// シミュレーション周波数（120Hz）
+pub const FIXED_TIMESTEP: f32 = 1.0 / 120.0;
+
+// 1フレームで実行する最大ステップ数（超過分は破棄し、処理落ちの連鎖を防ぐ）
+pub const MAX_STEPS_PER_FRAME: u32 = 8;
+
+#[derive(Clone, Debug)]
+pub struct FixedTimestep {
+    step: f32,
+    max_steps: u32,
+    accumulator: f32,   // 未消化の経過時間
+}
+
+impl Default for FixedTimestep {
+    fn default() -> Self {
+        Self::new(FIXED_TIMESTEP, MAX_STEPS_PER_FRAME)
+    }
+}
+
+impl FixedTimestep {
+    pub fn new(step: f32, max_steps: u32) -> Self {
+        Self {
+            step,
+            max_steps,
+            accumulator: 0.0,
+        }
+    }
+
+    pub fn step(&self) -> f32 {
+        self.step
+    }
+
+    // フレーム時間を加算し、今回実行するステップ数を返す
+    pub fn advance(&mut self, frame_time: f32) -> u32 {
+        if frame_time.is_finite() && frame_time > 0.0 {
+            self.accumulator += frame_time;
+        }
+
+        let steps = (self.accumulator / self.step).floor() as u32;
+        if steps > self.max_steps {
+            // タブ停止などで溜まった時間は破棄
+            self.accumulator = 0.0;
+            self.max_steps
+        } else {
+            self.accumulator -= steps as f32 * self.step;
+            steps
+        }
+    }
+
+    // 描画用の補間係数（前回ステップ 0.0 〜 今回ステップ 1.0）
+    pub fn alpha(&self) -> f32 {
+        (self.accumulator / self.step).clamp(0.0, 1.0)
+    }
+}
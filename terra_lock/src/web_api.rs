@@ -0,0 +1,105 @@
+// ホストページ連携API（WebAssembly埋め込み用）
+//
+// JavaScript側は docs/terra_lock_api.js の miniquad プラグインとして登録され、
+// Rust側からはイベント通知用の関数をインポートし、
+// JavaScript側からはコマンド用の関数をエクスポート経由で呼び出す。
+
+use std::sync::Mutex;
+
+use crate::game::{GameConfig, GameEvent};
+
+// JavaScript側プラグインとのバージョン整合性確認用
+// （miniquadのプラグインローダーは u32_to_semver で上位8bit.次の8bit.下位16bitの
+// "major.minor.patch" 形式に変換して比較するため、1 は JavaScript側の "0.0.1" に対応する）
+const API_VERSION: u32 = 1;
+
+// ホストページからのコマンド
+#[derive(Clone, Debug)]
+pub enum WebCommand {
+    SetConfig(&'static str, f32),
+    Start(u64),
+}
+
+// ゲームループで処理されるまでコマンドを保持するキュー
+static COMMANDS: Mutex<Vec<WebCommand>> = Mutex::new(Vec::new());
+
+fn push_command(command: WebCommand) {
+    if let Ok(mut commands) = COMMANDS.lock() {
+        commands.push(command);
+    }
+}
+
+// 保留中のコマンドを全て取り出す（毎フレーム呼び出し）
+pub fn take_commands() -> Vec<WebCommand> {
+    match COMMANDS.lock() {
+        Ok(mut commands) => std::mem::take(&mut *commands),
+        Err(_) => Vec::new(),
+    }
+}
+
+// プラグインのバージョン確認（miniquadのプラグイン初期化時に呼ばれる）
+#[no_mangle]
+pub extern "C" fn terra_lock_crate_version() -> u32 {
+    API_VERSION
+}
+
+// 設定変更（キーIDは GameConfig::KEYS のインデックス、未知のキーや不正な値の場合はfalse）
+#[no_mangle]
+pub extern "C" fn terra_lock_set_config(key_id: u32, value: f32) -> bool {
+    match GameConfig::KEYS.get(key_id as usize) {
+        // 値の妥当性は現在の設定に依存しないため、コマンドの処理を待たずに既定値の設定で検証する
+        Some(&key) if GameConfig::default().set(key, value) => {
+            push_command(WebCommand::SetConfig(key, value));
+            true
+        }
+        _ => false,
+    }
+}
+
+// シード指定でゲーム開始
+#[no_mangle]
+pub extern "C" fn terra_lock_start(seed: u32) {
+    push_command(WebCommand::Start(seed as u64));
+}
+
+// JavaScript側で実装されるコールバック
+#[cfg(target_arch = "wasm32")]
+extern "C" {
+    fn terra_lock_on_state_change(state: u32);
+    fn terra_lock_on_locks_fired(count: u32);
+    fn terra_lock_on_game_over(json_ptr: *const u8, json_len: u32);
+}
+
+// ゲームイベントをホストページへ通知
+pub fn notify(event: &GameEvent) {
+    match event {
+        GameEvent::StateChanged(state) => emit_state_change(state.id()),
+        GameEvent::LocksFired(count) => emit_locks_fired(*count as u32),
+        GameEvent::GameOver(stats) => emit_game_over(&stats.to_json()),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn emit_state_change(state: u32) {
+    unsafe { terra_lock_on_state_change(state) }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn emit_locks_fired(count: u32) {
+    unsafe { terra_lock_on_locks_fired(count) }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn emit_game_over(json: &str) {
+    unsafe { terra_lock_on_game_over(json.as_ptr(), json.len() as u32) }
+}
+
+// ネイティブ環境ではホストページが存在しないため通知しない
+#[cfg(not(target_arch = "wasm32"))]
+fn emit_state_change(_state: u32) {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn emit_locks_fired(_count: u32) {}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn emit_game_over(_json: &str) {}
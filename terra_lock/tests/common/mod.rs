@@ -0,0 +1,124 @@
+// テスト用ハーネス
+//
+// ウィンドウなしで Game を生成し、敵機の配置・入力の注入・固定ステップでの進行を行う。
+// 自動出現は無効化し、配置した敵機のみで検証する。
+
+#![allow(dead_code)] // テストファイルごとに使用する関数が異なる
+
+use macroquad::prelude::Vec2;
+use terra_lock::game::{self, EnemyType, Game, GameEvent, InputFrame};
+use terra_lock::timestep::FIXED_TIMESTEP;
+
+// 自機の初期位置
+pub const PLAYER_START: Vec2 = Vec2::new(400.0, 500.0);
+
+// 自機選択画面の各パネル内の位置
+pub const SHIP_PANELS: [Vec2; 3] = [
+    Vec2::new(140.0, 300.0),
+    Vec2::new(400.0, 300.0),
+    Vec2::new(660.0, 300.0),
+];
+
+pub struct Harness {
+    pub game: Game,
+    pub events: Vec<GameEvent>,  // step中に発生したイベント
+    mouse_pos: Vec2,
+}
+
+impl Harness {
+    // プレイ中の状態から開始（敵機なし、自機は初期位置）
+    pub fn playing() -> Self {
+        let mut harness = Self::title();
+        harness.game.start_game_with_seed(1);
+        harness.game.set_enemy_spawning(false);
+        harness.events.extend(harness.game.drain_events());
+        harness
+    }
+
+    // 自機を選択してプレイ中の状態から開始
+    pub fn playing_as(ship: usize) -> Self {
+        let mut harness = Self::title();
+        assert!(harness.game.select_ship(ship), "unknown ship {}", ship);
+        harness.game.start_game_with_seed(1);
+        harness.game.set_enemy_spawning(false);
+        harness.events.extend(harness.game.drain_events());
+        harness
+    }
+
+    // タイトル画面から開始
+    pub fn title() -> Self {
+        game::set_logging(false);
+        Self {
+            game: Game::new(),
+            events: Vec::new(),
+            mouse_pos: PLAYER_START,
+        }
+    }
+
+    // 静止した敵機を配置して添字を返す
+    pub fn spawn(&mut self, enemy_type: EnemyType, position: Vec2) -> usize {
+        self.spawn_moving(enemy_type, position, 0.0)
+    }
+
+    pub fn spawn_moving(&mut self, enemy_type: EnemyType, position: Vec2, base_speed: f32) -> usize {
+        self.game.spawn_enemy(enemy_type, position, base_speed).expect("enemy pool is full")
+    }
+
+    // 1ステップ進める
+    pub fn step(&mut self, mouse_pos: Vec2, left_button_down: bool) {
+        self.step_frame(InputFrame { mouse_pos, left_button_down, bomb_button_down: false });
+    }
+
+    pub fn step_frame(&mut self, frame: InputFrame) {
+        self.mouse_pos = frame.mouse_pos;
+        self.game.step(FIXED_TIMESTEP, frame);
+        self.events.extend(self.game.drain_events());
+    }
+
+    // 指定秒数、同じ入力で進める
+    pub fn run(&mut self, seconds: f32, mouse_pos: Vec2, left_button_down: bool) {
+        for _ in 0..steps_for(seconds) {
+            self.step(mouse_pos, left_button_down);
+        }
+    }
+
+    // 現在のマウス位置のまま、ボタンを離して進める
+    pub fn wait(&mut self, seconds: f32) {
+        self.run(seconds, self.mouse_pos, false);
+    }
+
+    // 短押し（1ステップ押して離す）
+    pub fn click(&mut self, mouse_pos: Vec2) {
+        self.step(mouse_pos, true);
+        self.step(mouse_pos, false);
+    }
+
+    // 長押し（ボタンは押したまま）
+    pub fn hold(&mut self, mouse_pos: Vec2, seconds: f32) {
+        self.run(seconds, mouse_pos, true);
+    }
+
+    pub fn release(&mut self) {
+        self.step(self.mouse_pos, false);
+    }
+
+    // ボム（1ステップ押して離す、左ボタンは離したまま）
+    pub fn bomb(&mut self) {
+        self.step_frame(InputFrame { mouse_pos: self.mouse_pos, left_button_down: false, bomb_button_down: true });
+        self.release();
+    }
+
+    // ロックオン中の敵機の位置
+    pub fn locked_positions(&self) -> Vec<Vec2> {
+        let observation = self.game.observe();
+        self.game
+            .locked_enemies()
+            .iter()
+            .map(|&idx| observation.enemies[idx].position)
+            .collect()
+    }
+}
+
+pub fn steps_for(seconds: f32) -> usize {
+    (seconds / FIXED_TIMESTEP).round() as usize
+}
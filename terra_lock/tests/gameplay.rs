@@ -0,0 +1,887 @@
+// ゲームロジックの結合テスト（状態遷移・通常レーザー・ロックオン・被弾）
+
+mod common;
+
+use common::{Harness, PLAYER_START, SHIP_PANELS};
+use macroquad::prelude::Vec2;
+use terra_lock::game::{EnemyType, GameEvent, GameState, InputFrame};
+
+// 自機の真上を避けた、ワイヤーフレーム（半径100px）内の配置
+const LOCK_TARGETS: [Vec2; 3] = [
+    Vec2::new(330.0, 460.0),
+    Vec2::new(470.0, 460.0),
+    Vec2::new(360.0, 420.0),
+];
+
+#[test]
+fn click_on_title_starts_game() {
+    let mut harness = Harness::title();
+    assert_eq!(harness.game.state(), GameState::Title);
+
+    harness.click(PLAYER_START);
+    assert_eq!(harness.game.state(), GameState::ShipSelect);
+
+    // パネル外のクリックは無視
+    harness.click(PLAYER_START);
+    assert_eq!(harness.game.state(), GameState::ShipSelect);
+
+    harness.click(SHIP_PANELS[2]);
+
+    assert_eq!(harness.game.state(), GameState::Playing);
+    assert_eq!(harness.game.ship().name, "SPECTER");
+    assert_eq!(harness.game.stats().ship, "SPECTER");
+    assert_eq!(harness.game.config().max_targets, 8);
+    assert!(matches!(
+        harness.events[..],
+        [GameEvent::StateChanged(GameState::ShipSelect), GameEvent::StateChanged(GameState::Playing)]
+    ));
+}
+
+#[test]
+fn host_config_overrides_survive_ship_selection() {
+    // 自機の選択前に変更した項目は自機の性能で上書きされず、変更していない項目は自機の値になる
+    let mut harness = Harness::title();
+    assert!(harness.game.set_config("lockRadius", 150.0));
+    assert!(harness.game.select_ship(2));
+    assert_eq!(harness.game.config().lock_radius, 150.0);
+    assert_eq!(harness.game.lock_system().radius, 150.0);
+    assert_eq!(harness.game.config().max_targets, 8);
+
+    // 別の自機を選び直しても維持される
+    assert!(harness.game.select_ship(0));
+    assert_eq!(harness.game.config().lock_radius, 150.0);
+}
+
+#[test]
+fn ship_decides_normal_shot_pattern() {
+    // 扇状に3発（中央は真上、左右は斜め）
+    let mut harness = Harness::playing_as(1);
+    harness.click(PLAYER_START);
+    let lasers = harness.game.normal_lasers();
+    assert_eq!(lasers.len(), 3);
+    assert_eq!(harness.game.stats().normal_shots_fired, 3);
+    assert!(lasers.iter().any(|laser| laser.velocity.x < 0.0));
+    assert!(lasers.iter().any(|laser| laser.velocity.x == 0.0));
+    assert!(lasers.iter().any(|laser| laser.velocity.x > 0.0));
+
+    // 左右に並べて平行に2発
+    let mut harness = Harness::playing_as(2);
+    harness.click(PLAYER_START);
+    let lasers = harness.game.normal_lasers();
+    assert_eq!(lasers.len(), 2);
+    assert!(lasers.iter().all(|laser| laser.velocity.x == 0.0));
+    assert!(lasers[0].position.x != lasers[1].position.x);
+}
+
+#[test]
+fn ship_speed_limits_how_fast_the_player_follows_the_mouse() {
+    let target = Vec2::new(100.0, 500.0);
+
+    // 既定の自機は即座にマウス位置へ
+    let mut harness = Harness::playing();
+    harness.step(target, false);
+    assert_eq!(harness.game.player_position(), target);
+
+    // 速度600px/秒の自機は1ステップで5pxだけ移動
+    let mut harness = Harness::playing_as(2);
+    harness.step(target, false);
+    assert!((harness.game.player_position().x - 395.0).abs() < 0.01);
+    harness.wait(1.0);
+    assert_eq!(harness.game.player_position(), target);
+}
+
+#[test]
+fn each_ship_keeps_its_own_high_scores() {
+    for (ship, kills) in [(1, 1), (2, 2)] {
+        let mut harness = Harness::playing_as(ship);
+        for _ in 0..kills {
+            harness.spawn(EnemyType::Straight, Vec2::new(400.0, 300.0));
+            harness.click(PLAYER_START);
+            harness.wait(1.0);
+        }
+        harness.spawn(EnemyType::Straight, PLAYER_START);
+        harness.step(PLAYER_START, false);
+        assert_eq!(harness.game.state(), GameState::GameOver);
+
+        assert_eq!(harness.game.high_scores(ship).scores(), &[kills * 100]);
+        assert!(harness.game.high_scores(0).scores().is_empty());
+    }
+}
+
+#[test]
+fn normal_laser_destroys_enemy_above_player() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, Vec2::new(400.0, 300.0));
+
+    harness.click(PLAYER_START);
+    harness.wait(1.0);
+
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.score(), 100);
+    assert_eq!(harness.game.stats().normal_shots_fired, 1);
+    assert_eq!(harness.game.stats().normal_shots_hit, 1);
+    assert_eq!(harness.game.stats().kills_by_type[EnemyType::Straight.index()], 1);
+}
+
+#[test]
+fn normal_laser_misses_enemy_beside_its_path() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, Vec2::new(412.0, 300.0));
+
+    harness.click(PLAYER_START);
+    harness.wait(1.0);
+
+    assert_eq!(harness.game.enemy_count(), 1);
+    assert_eq!(harness.game.score(), 0);
+}
+
+#[test]
+fn normal_fire_is_limited_by_the_fire_interval() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("fireInterval", 0.25));
+
+    // 発射間隔内の2回目のクリックは無視
+    harness.click(PLAYER_START);
+    harness.click(PLAYER_START);
+    assert_eq!(harness.game.stats().normal_shots_fired, 1);
+
+    harness.wait(0.25);
+    harness.click(PLAYER_START);
+    assert_eq!(harness.game.stats().normal_shots_fired, 2);
+}
+
+#[test]
+fn auto_fire_shoots_while_held_until_the_lock_threshold() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("fireMode", 1.0));
+    assert!(harness.game.set_config("longPressTime", 1.0));
+
+    // 0.1秒間隔で連射し、長押し判定（1秒）に達するとロックオンへ移行して停止
+    harness.hold(PLAYER_START, 0.55);
+    assert_eq!(harness.game.stats().normal_shots_fired, 6);
+    assert!(harness.game.weapon().heat > 0.0);
+    harness.hold(PLAYER_START, 0.95);
+    assert!(harness.game.lock_system().active);
+    assert_eq!(harness.game.stats().normal_shots_fired, 10);
+}
+
+#[test]
+fn auto_fire_stops_while_the_weapon_is_overheated() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("fireMode", 1.0));
+    assert!(harness.game.set_config("longPressTime", 20.0));
+
+    let mut steps = 0;
+    while !harness.game.weapon().overheated {
+        harness.step(PLAYER_START, true);
+        steps += 1;
+        assert!(steps < common::steps_for(10.0), "weapon never overheated");
+    }
+
+    // 熱が0に戻るまで発射しない
+    let shots = harness.game.stats().normal_shots_fired;
+    harness.hold(PLAYER_START, 1.0);
+    assert!(harness.game.weapon().overheated);
+    assert_eq!(harness.game.stats().normal_shots_fired, shots);
+
+    harness.hold(PLAYER_START, 1.0);
+    assert!(!harness.game.weapon().overheated);
+    assert!(harness.game.stats().normal_shots_fired > shots);
+}
+
+#[test]
+fn charge_shot_fires_on_release_and_pierces_destroyed_enemies() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("fireMode", 2.0));
+    for y in [300.0, 220.0, 140.0, 60.0] {
+        harness.spawn(EnemyType::Straight, Vec2::new(400.0, y));
+    }
+
+    // 短いクリックはチャージなしの1発（最も近い1機のみ撃破）
+    harness.step(PLAYER_START, true);
+    assert!(harness.game.normal_lasers().is_empty());
+    harness.release();
+    assert_eq!(harness.game.normal_lasers().len(), 1);
+    harness.wait(1.0);
+    assert_eq!(harness.game.enemy_count(), 3);
+
+    // 長押し判定の直前で離すと、大きく貫通するレーザー（2機を貫通して3機目まで撃破）
+    harness.hold(PLAYER_START, 0.19);
+    assert!(harness.game.weapon().charge > 0.9);
+    harness.release();
+    assert_eq!(harness.game.normal_lasers()[0].pierce, 2);
+    harness.wait(1.5);
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.stats().normal_shots_hit, 2);
+
+    // 長押し判定に達した場合はロックオンへ移行し、離しても発射しない
+    harness.hold(PLAYER_START, 0.5);
+    assert_eq!(harness.game.weapon().charge, 0.0);
+    harness.release();
+    assert_eq!(harness.game.stats().normal_shots_fired, 2);
+}
+
+#[test]
+fn long_press_locks_enemies_inside_wireframe_only() {
+    let mut harness = Harness::playing();
+    for position in LOCK_TARGETS {
+        harness.spawn(EnemyType::Straight, position);
+    }
+    harness.spawn(EnemyType::Straight, Vec2::new(200.0, 300.0)); // ワイヤーフレーム外
+
+    // 長押し判定（既定0.2秒）前はロックオンしない
+    harness.hold(PLAYER_START, 0.1);
+    assert_eq!(harness.game.locked_count(), 0);
+
+    // ワイヤーフレーム展開後もロックオン時間（既定0.15秒）に達するまではロックオンしない
+    harness.hold(PLAYER_START, 0.2);
+    assert!(harness.game.observe().lock.active);
+    assert_eq!(harness.game.locked_count(), 0);
+
+    harness.hold(PLAYER_START, 0.1);
+    assert_eq!(harness.locked_positions(), LOCK_TARGETS.to_vec());
+}
+
+#[test]
+fn locks_are_kept_in_acquisition_order() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    harness.hold(PLAYER_START, 0.4);
+    assert_eq!(harness.locked_positions(), vec![LOCK_TARGETS[0]]);
+
+    // 後から入った敵機は既存のロックオンの後ろに追加される（毎ステップ作り直さない）
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[1]);
+    harness.hold(PLAYER_START, 0.1);
+    assert_eq!(harness.locked_positions(), vec![LOCK_TARGETS[0]]);
+
+    harness.hold(PLAYER_START, 0.1);
+    assert_eq!(harness.locked_positions(), vec![LOCK_TARGETS[0], LOCK_TARGETS[1]]);
+}
+
+#[test]
+fn lock_order_decides_which_enemies_fill_the_last_slots() {
+    // 添字順は遠い敵機が先、ワイヤーフレーム中心（自機位置）からの距離は近い敵機が先
+    let far = LOCK_TARGETS[2];
+    let near = Vec2::new(440.0, 480.0);
+
+    for (order, expected) in [(0.0, far), (1.0, near)] {
+        let mut harness = Harness::playing();
+        assert!(harness.game.set_config("maxTargets", 1.0));
+        assert!(harness.game.set_config("lockOrder", order));
+        harness.spawn(EnemyType::Straight, far);
+        harness.spawn(EnemyType::Straight, near);
+
+        // 両機が同じステップでロックオン時間に達し、残り1枠を優先順で割り当てる
+        harness.hold(PLAYER_START, 0.5);
+
+        assert_eq!(harness.locked_positions(), vec![expected], "lockOrder {}", order);
+    }
+}
+
+#[test]
+fn lock_count_is_limited_by_max_targets() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("maxTargets", 2.0));
+    for position in LOCK_TARGETS {
+        harness.spawn(EnemyType::Straight, position);
+    }
+
+    harness.hold(PLAYER_START, 0.75);
+
+    assert_eq!(harness.locked_positions(), LOCK_TARGETS[..2].to_vec());
+}
+
+#[test]
+fn moving_wireframe_away_releases_locks() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    harness.hold(PLAYER_START, 0.75);
+    assert_eq!(harness.game.locked_count(), 1);
+
+    harness.hold(Vec2::new(600.0, 500.0), 0.1);
+    assert_eq!(harness.game.locked_count(), 0);
+    assert!(!harness.game.observe().enemies[0].is_locked);
+}
+
+#[test]
+fn sweep_mode_keeps_every_enemy_the_wireframe_touched_until_release() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("lockMode", 1.0));
+    let touched = [Vec2::new(250.0, 330.0), Vec2::new(420.0, 330.0), Vec2::new(600.0, 330.0)];
+    for position in touched {
+        harness.spawn(EnemyType::Straight, position);
+    }
+
+    let start = Vec2::new(200.0, 400.0);
+    harness.hold(start, 0.25);
+    assert_eq!(harness.locked_positions(), vec![touched[0]]);
+
+    // 1ステップで大きく移動しても、通過した範囲の敵機をロックオンする
+    harness.hold(Vec2::new(600.0, 400.0), 0.1);
+    assert_eq!(harness.locked_positions(), touched.to_vec());
+
+    harness.release();
+    assert!(harness.events.iter().any(|event| matches!(event, GameEvent::LocksFired(3))));
+}
+
+#[test]
+fn lock_shape_decides_which_enemies_can_be_locked() {
+    let ahead = Vec2::new(420.0, 200.0);      // 自機の前方の遠く（通常レーザーの射線外）
+    let same_row = Vec2::new(100.0, 490.0);   // 自機と同じ高さの画面左端
+    let nearby = Vec2::new(470.0, 440.0);     // 自機の斜め前
+
+    // 1: 前方三角形, 2: 横帯, 3: 拡大する円
+    for (shape, expected) in [(1.0, vec![ahead]), (2.0, vec![same_row]), (3.0, vec![nearby])] {
+        let mut harness = Harness::playing();
+        assert!(harness.game.set_config("lockShape", shape));
+        for position in [ahead, same_row, nearby] {
+            harness.spawn(EnemyType::Straight, position);
+        }
+
+        harness.hold(PLAYER_START, 1.0);
+
+        assert_eq!(harness.locked_positions(), expected, "lockShape {}", shape);
+    }
+}
+
+#[test]
+fn cone_wireframe_starts_at_the_player_not_the_cursor() {
+    // 画面外のカーソルには自機が追従できないが、三角形の頂点は自機位置のまま
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("lockShape", 1.0));
+    let cursor = Vec2::new(400.0, 700.0);
+    harness.hold(cursor, 1.0);
+
+    let lock_system = harness.game.lock_system();
+    assert!(lock_system.active);
+    assert_eq!(lock_system.center, harness.game.player_position());
+    assert!(lock_system.center != cursor);
+}
+
+#[test]
+fn releasing_fires_homing_lasers_that_destroy_locked_enemies() {
+    let mut harness = Harness::playing();
+    for position in LOCK_TARGETS {
+        harness.spawn(EnemyType::Straight, position);
+    }
+
+    harness.hold(PLAYER_START, 0.75);
+    harness.release();
+
+    assert!(harness.events.iter().any(|event| matches!(event, GameEvent::LocksFired(3))));
+    assert_eq!(harness.game.locked_count(), 0);
+    assert_eq!(harness.game.observe().homing_lasers.len(), 3);
+
+    harness.wait(2.0);
+
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert!(harness.game.observe().homing_lasers.is_empty());
+    assert_eq!(harness.game.stats().total_kills(), 3);
+    assert_eq!(harness.game.stats().volleys_by_size[2], 1);
+    assert!(harness.game.score() >= 3 * 200);
+}
+
+#[test]
+fn steering_lasers_turn_at_a_limited_rate_towards_moving_targets() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("homingTrajectory", 1.0));
+    assert!(harness.game.set_config("homingTurnRate", 4.0));
+    harness.spawn_moving(EnemyType::Zigzag, Vec2::new(350.0, 420.0), 40.0);
+
+    harness.hold(PLAYER_START, 0.4);
+    harness.release();
+    assert_eq!(harness.game.homing_lasers().len(), 1);
+
+    // 旋回速度は発射直後 4ラジアン/秒、以降は時間とともに増加（0.2秒後で1.4倍）
+    let mut heading = harness.game.homing_lasers()[0].heading;
+    for _ in 0..24 {
+        harness.step(PLAYER_START, false);
+        let Some(laser) = harness.game.homing_lasers().first() else {
+            break;
+        };
+        assert!(laser.heading.angle_between(heading).abs() <= 4.0 * 1.4 / 120.0 + 1e-4);
+        heading = laser.heading;
+    }
+
+    harness.wait(2.0);
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.stats().kills_by_type[EnemyType::Zigzag.index()], 1);
+}
+
+#[test]
+fn volley_bonus_is_awarded_when_its_last_laser_finishes() {
+    let mut harness = Harness::playing();
+    // 距離の異なる2機（レーザーの到達は別のステップ）
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[2]);
+
+    harness.hold(PLAYER_START, 0.4);
+    harness.release();
+    while harness.game.enemy_count() == 2 {
+        harness.wait(0.01);
+    }
+    assert_eq!(harness.game.enemy_count(), 1);
+    assert_eq!(harness.game.score(), 200);
+    assert!(harness.game.bonus_displays().is_empty());
+
+    harness.wait(1.0);
+
+    // 200点 × 2本 + 2本の一斉発射ボーナス300点
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.score(), 700);
+    assert_eq!(harness.game.stats().bonus_points, 300);
+    assert_eq!(harness.game.bonus_displays().len(), 1);
+}
+
+#[test]
+fn holding_longer_stacks_locks_on_the_same_enemy() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("maxLocksPerTarget", 3.0));
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    // 長押し判定0.2秒 + ロックオン0.15秒で1つ目、以降0.3秒ごとに重ねる
+    harness.hold(PLAYER_START, 0.45);
+    assert_eq!(harness.game.locked_enemies(), &[0]);
+    harness.hold(PLAYER_START, 0.3);
+    assert_eq!(harness.game.locked_enemies(), &[0, 0]);
+    harness.hold(PLAYER_START, 0.6);
+    assert_eq!(harness.game.locked_enemies(), &[0, 0, 0]);
+    assert_eq!(harness.game.enemies()[0].lock_stack, 3);
+
+    // 重ねたレーザーは時間差で発射され、それぞれ異なる曲線を描く
+    harness.release();
+    assert!(harness.events.iter().any(|event| matches!(event, GameEvent::LocksFired(3))));
+    let lasers = harness.game.homing_lasers();
+    assert_eq!(lasers.len(), 3);
+    assert!(lasers[0].launch_time < lasers[1].launch_time && lasers[1].launch_time < lasers[2].launch_time);
+    assert!(lasers[0].progress > 0.0);
+    assert_eq!(lasers[2].progress, 0.0);
+    assert_ne!(lasers[1].trajectory, lasers[2].trajectory);
+
+    harness.wait(2.0);
+
+    // 耐久力1の敵機は1本目で撃破され、目標を失った残り2本は得点なしで消滅
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert!(harness.game.homing_lasers().is_empty());
+    assert_eq!(harness.game.stats().volleys_by_size[2], 1);
+    assert_eq!(harness.game.score(), 200);
+    assert_eq!(harness.game.stats().bonus_points, 0);
+}
+
+// 1機に2つ重ねたロックオンを発射し、1本目が敵機を撃破した直後まで進める（2本目は飛行中）
+fn destroy_the_target_of_a_stacked_volley(harness: &mut Harness) {
+    assert!(harness.game.set_config("maxLocksPerTarget", 2.0));
+    let enemy_count = harness.game.enemy_count();
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    harness.hold(PLAYER_START, 0.7);
+    assert_eq!(harness.game.locked_count(), 2);
+    harness.release();
+    while harness.game.enemy_count() > enemy_count {
+        harness.wait(0.01);
+    }
+    assert_eq!(harness.game.score(), 200);
+    assert_eq!(harness.game.homing_lasers().len(), 1);
+    assert_eq!(harness.game.homing_lasers()[0].target_enemy_id, None);
+}
+
+#[test]
+fn lasers_that_lose_their_target_hit_whatever_is_at_the_endpoint() {
+    let mut harness = Harness::playing();
+    destroy_the_target_of_a_stacked_volley(&mut harness);
+
+    // 2本目は撃破された敵機の位置まで飛行し、そこにいる敵機に命中する
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+    harness.wait(1.0);
+
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.score(), 2 * 200 + 300);
+    assert!(harness.game.laser_fizzles().is_empty());
+}
+
+#[test]
+fn lasers_that_lose_their_target_retarget_the_nearest_unlocked_enemy() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("homingTargetLoss", 1.0));
+    harness.spawn(EnemyType::Straight, Vec2::new(750.0, 50.0));
+    harness.spawn(EnemyType::Straight, Vec2::new(250.0, 380.0));
+    destroy_the_target_of_a_stacked_volley(&mut harness);
+
+    // 次のステップで近い方の敵機へ目標を変更
+    harness.step(PLAYER_START, false);
+    let laser = &harness.game.homing_lasers()[0];
+    let target = laser.target_enemy_id.expect("retargeted");
+    assert_eq!(harness.game.enemies()[target].position, Vec2::new(250.0, 380.0));
+
+    harness.wait(2.0);
+    assert_eq!(harness.game.enemy_count(), 1);
+    assert_eq!(harness.game.enemies()[0].position, Vec2::new(750.0, 50.0));
+    assert_eq!(harness.game.score(), 2 * 200 + 300);
+}
+
+#[test]
+fn lasers_that_lose_their_target_can_fizzle_out() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("homingTargetLoss", 2.0));
+    destroy_the_target_of_a_stacked_volley(&mut harness);
+
+    // 2本目はその場で消滅し、エフェクトを表示（命中なしのため得点・ボーナスなし）
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+    harness.step(PLAYER_START, false);
+    assert!(harness.game.homing_lasers().is_empty());
+    assert_eq!(harness.game.laser_fizzles().len(), 1);
+
+    harness.wait(1.0);
+    assert_eq!(harness.game.enemy_count(), 1);
+    assert_eq!(harness.game.score(), 200);
+    assert!(harness.game.bonus_displays().is_empty());
+    assert!(harness.game.laser_fizzles().is_empty());
+}
+
+#[test]
+fn stacked_locks_count_towards_max_targets() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("maxLocksPerTarget", 3.0));
+    assert!(harness.game.set_config("maxTargets", 3.0));
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    // 1機に2つ重ねた後に入った敵機が残り1枠を得る
+    harness.hold(PLAYER_START, 0.75);
+    assert_eq!(harness.game.locked_count(), 2);
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[1]);
+    harness.hold(PLAYER_START, 0.15);
+    assert_eq!(harness.game.locked_enemies(), &[0, 0, 1]);
+
+    harness.hold(PLAYER_START, 1.0);
+    assert_eq!(harness.game.locked_count(), 3);
+}
+
+#[test]
+fn open_wireframe_drains_lock_energy_and_it_refills_while_closed() {
+    let mut harness = Harness::playing();
+    let full = harness.game.lock_system().energy;
+
+    // 長押し判定（0.2秒）後の1秒間、8/秒で消費
+    harness.hold(PLAYER_START, 1.2);
+    assert!((harness.game.lock_system().energy - (full - 8.0)).abs() < 0.1);
+
+    // 閉じている間は12/秒で回復
+    harness.release();
+    harness.wait(1.0);
+    assert_eq!(harness.game.lock_system().energy, full);
+}
+
+#[test]
+fn fired_lasers_cost_energy_and_kills_refill_it() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("lockEnergyDrain", 0.0));
+    assert!(harness.game.set_config("lockEnergyRegen", 0.0));
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[1]);
+
+    // 2本で 4 × 2 を消費し、2機の撃破で 3 × 2 を回復
+    harness.hold(PLAYER_START, 0.4);
+    harness.release();
+    assert_eq!(harness.game.lock_system().energy, 100.0 - 8.0);
+    harness.wait(1.0);
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.lock_system().energy, 100.0 - 8.0 + 6.0);
+}
+
+#[test]
+fn locks_need_energy_for_every_laser_in_the_volley() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("lockEnergyPerLock", 40.0));
+    for position in LOCK_TARGETS {
+        harness.spawn(EnemyType::Straight, position);
+    }
+
+    // 残量100では3本目（合計120）を賄えない
+    harness.hold(PLAYER_START, 0.75);
+    assert_eq!(harness.game.locked_count(), 2);
+    harness.release();
+    assert!(harness.events.iter().any(|event| matches!(event, GameEvent::LocksFired(2))));
+}
+
+#[test]
+fn running_out_of_energy_fires_the_held_locks_and_closes_the_wireframe() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("lockEnergyDrain", 100.0));
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    // 展開から1秒で尽き、長押し中でもロックオン済みの敵機へ発射
+    harness.hold(PLAYER_START, 1.25);
+    assert!(harness.events.iter().any(|event| matches!(event, GameEvent::LocksFired(1))));
+    assert!(!harness.game.lock_system().active);
+    assert_eq!(harness.game.homing_lasers().len(), 1);
+
+    // 展開に必要な残量20まで回復すると再展開（12/秒と撃破の3で約1.4秒）
+    let mut steps = 0;
+    while !harness.game.lock_system().active {
+        harness.step(PLAYER_START, true);
+        steps += 1;
+        assert!(steps < common::steps_for(3.0), "wireframe never reopened");
+    }
+    assert!(steps >= common::steps_for(1.2));
+}
+
+#[test]
+fn bomb_destroys_on_screen_enemies_for_reduced_points() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, Vec2::new(100.0, 100.0));
+    harness.spawn(EnemyType::Homing, Vec2::new(700.0, 300.0));
+    // 出現前の画面外の敵機は対象外
+    harness.spawn(EnemyType::Straight, Vec2::new(400.0, -40.0));
+    assert_eq!(harness.game.bombs(), 3);
+
+    harness.bomb();
+
+    assert_eq!(harness.game.enemy_count(), 1);
+    assert_eq!(harness.game.enemies()[0].position, Vec2::new(400.0, -40.0));
+    assert_eq!(harness.game.score(), 100);
+    assert_eq!(harness.game.stats().total_kills(), 2);
+    assert_eq!(harness.game.stats().bombs_used, 1);
+    assert_eq!(harness.game.bombs(), 2);
+    assert!(harness.game.bomb_blast().is_some());
+
+    // 押し続けても1回のみ、残数がなくなると発動しない
+    harness.step_frame(InputFrame {
+        mouse_pos: PLAYER_START,
+        left_button_down: false,
+        bomb_button_down: true,
+    });
+    harness.step_frame(InputFrame {
+        mouse_pos: PLAYER_START,
+        left_button_down: false,
+        bomb_button_down: true,
+    });
+    assert_eq!(harness.game.bombs(), 1);
+    harness.bomb();
+    harness.bomb();
+    assert_eq!(harness.game.bombs(), 0);
+    assert_eq!(harness.game.stats().bombs_used, 3);
+}
+
+#[test]
+fn bomb_grants_brief_invulnerability() {
+    let mut harness = Harness::playing();
+    harness.bomb();
+    harness.spawn(EnemyType::Homing, Vec2::new(400.0, 485.0));
+
+    harness.wait(1.0);
+    assert_eq!(harness.game.state(), GameState::Playing);
+
+    harness.wait(0.6);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+}
+
+#[test]
+fn bomb_stock_is_configurable_and_refilled_on_restart() {
+    let mut harness = Harness::playing();
+    assert!(harness.game.set_config("bombStock", 0.0));
+    harness.spawn(EnemyType::Straight, Vec2::new(100.0, 100.0));
+
+    // 設定は次のゲーム開始から有効
+    harness.bomb();
+    assert_eq!(harness.game.bombs(), 2);
+    assert_eq!(harness.game.enemy_count(), 0);
+
+    harness.spawn(EnemyType::Straight, PLAYER_START);
+    harness.wait(2.0);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+    harness.click(PLAYER_START);
+
+    harness.spawn(EnemyType::Straight, Vec2::new(100.0, 100.0));
+    harness.bomb();
+    assert_eq!(harness.game.bombs(), 0);
+    assert_eq!(harness.game.enemy_count(), 1);
+}
+
+#[test]
+fn shooters_stop_and_fire_at_the_player() {
+    let mut harness = Harness::playing();
+    harness.spawn_moving(EnemyType::Shooter, Vec2::new(400.0, 100.0), 120.0);
+
+    harness.wait(1.0);
+    assert_eq!(harness.game.enemies()[0].velocity, Vec2::ZERO);
+    let [bullet] = harness.game.enemy_bullets() else {
+        panic!("expected one bullet, got {}", harness.game.enemy_bullets().len());
+    };
+    assert!(bullet.velocity.normalize().dot(Vec2::Y) > 0.99);
+
+    harness.wait(2.0);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+    assert_eq!(harness.game.stats().death_cause, Some(EnemyType::Shooter));
+}
+
+#[test]
+fn bomb_clears_enemy_bullets() {
+    let mut harness = Harness::playing();
+    harness.spawn_moving(EnemyType::Carrier, Vec2::new(100.0, 100.0), 0.0);
+    harness.spawn_moving(EnemyType::Shooter, Vec2::new(700.0, 100.0), 120.0);
+    harness.wait(1.0);
+    assert!(!harness.game.enemy_bullets().is_empty());
+
+    harness.bomb();
+    assert!(harness.game.enemy_bullets().is_empty());
+}
+
+#[test]
+fn splitters_break_into_drones() {
+    let mut harness = Harness::playing();
+    harness.spawn_moving(EnemyType::Splitter, Vec2::new(400.0, 300.0), 60.0);
+
+    harness.click(PLAYER_START);
+    harness.wait(0.7);
+
+    let kills = harness.game.stats().kills_by_type;
+    assert_eq!(kills[EnemyType::Splitter.index()], 1);
+    assert_eq!(harness.game.enemy_count(), 3);
+    assert!(harness.game.enemies().iter().all(|enemy| enemy.enemy_type == EnemyType::Drone));
+}
+
+#[test]
+fn shielded_enemies_block_normal_lasers() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Shielded, Vec2::new(400.0, 300.0));
+
+    harness.click(PLAYER_START);
+    harness.wait(1.0);
+
+    assert_eq!(harness.game.enemy_count(), 1);
+    assert_eq!(harness.game.enemies()[0].hp, 2);
+    assert!(harness.game.normal_lasers().is_empty());
+    assert_eq!(harness.game.stats().normal_shots_hit, 0);
+}
+
+#[test]
+fn shielded_enemies_take_a_lock_for_each_hit_point() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Shielded, LOCK_TARGETS[0]);
+
+    // 耐久力2の敵機には既定の設定でも2つ重ねられる
+    harness.hold(PLAYER_START, 0.8);
+    assert_eq!(harness.game.enemies()[0].lock_stack, 2);
+    harness.release();
+    harness.wait(2.0);
+
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.stats().kills_by_type[EnemyType::Shielded.index()], 1);
+}
+
+#[test]
+fn kamikazes_charge_at_the_player_when_locked() {
+    let mut harness = Harness::playing();
+    harness.spawn_moving(EnemyType::Kamikaze, LOCK_TARGETS[2], 60.0);
+    harness.wait(0.1);
+    assert!(!harness.game.enemies()[0].charging);
+
+    harness.hold(PLAYER_START, 0.4);
+
+    let kamikaze = &harness.game.enemies()[0];
+    assert!(kamikaze.charging);
+    let to_player = (PLAYER_START - kamikaze.position).normalize();
+    assert!(kamikaze.velocity.normalize().dot(to_player) > 0.95);
+    assert!(kamikaze.velocity.length() > 150.0);
+}
+
+#[test]
+fn carriers_launch_drones_toward_the_player() {
+    let mut harness = Harness::playing();
+    harness.spawn_moving(EnemyType::Carrier, Vec2::new(200.0, 60.0), 120.0);
+
+    harness.wait(1.0);
+
+    let enemies = harness.game.enemies();
+    assert_eq!(enemies.len(), 2);
+    assert_eq!(enemies[0].velocity, Vec2::ZERO);
+    assert_eq!(enemies[1].enemy_type, EnemyType::Drone);
+    let to_player = (PLAYER_START - enemies[1].position).normalize();
+    assert!(enemies[1].velocity.normalize().dot(to_player) > 0.95);
+}
+
+#[test]
+fn entity_accessors_expose_lasers_in_flight() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+
+    harness.hold(PLAYER_START, 0.4);
+    harness.release();
+    harness.click(PLAYER_START);
+
+    let game = &harness.game;
+    assert_eq!(game.enemies().len(), 1);
+    assert_eq!(game.enemies()[0].position, LOCK_TARGETS[0]);
+    assert!(!game.lock_system().active);
+
+    let [laser] = game.homing_lasers() else {
+        panic!("expected one homing laser, got {}", game.homing_lasers().len());
+    };
+    assert_eq!(laser.target_enemy_id, Some(0));
+    assert_eq!(laser.start_pos, game.player().position);
+    assert!(laser.progress > 0.0 && laser.progress < 1.0);
+
+    // 長押しの押し始めとクリックでそれぞれ1発
+    assert_eq!(game.normal_lasers().len(), 2);
+}
+
+#[test]
+fn homing_lasers_keep_their_targets_when_other_enemies_are_removed() {
+    let mut harness = Harness::playing();
+    // 先頭の敵機はレーザー飛行中に画面下へ退場し、末尾の敵機がその添字へ移動する
+    harness.spawn_moving(EnemyType::Straight, Vec2::new(100.0, 600.0), 120.0);
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[0]);
+    harness.spawn(EnemyType::Straight, LOCK_TARGETS[1]);
+
+    harness.hold(PLAYER_START, 0.4);
+    harness.release();
+    assert_eq!(harness.game.observe().homing_lasers.len(), 2);
+
+    harness.wait(0.1);
+    assert_eq!(harness.game.enemy_count(), 2);
+    assert_eq!(harness.game.observe().homing_lasers.len(), 2);
+
+    harness.wait(2.0);
+
+    assert_eq!(harness.game.enemy_count(), 0);
+    assert_eq!(harness.game.stats().total_kills(), 2);
+}
+
+#[test]
+fn collision_with_enemy_ends_game() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Homing, Vec2::new(400.0, 485.0));
+
+    harness.step(PLAYER_START, false);
+
+    assert_eq!(harness.game.state(), GameState::GameOver);
+    assert_eq!(harness.game.stats().death_cause, Some(EnemyType::Homing));
+    assert!(harness.events.iter().any(|event| matches!(event, GameEvent::GameOver(_))));
+}
+
+#[test]
+fn enemy_near_player_corner_does_not_end_game() {
+    let mut harness = Harness::playing();
+    // 外接矩形内だが自機の角から半径10px以上離れた位置
+    harness.spawn(EnemyType::Straight, PLAYER_START + Vec2::new(18.0, -15.0));
+
+    harness.step(PLAYER_START, false);
+
+    assert_eq!(harness.game.state(), GameState::Playing);
+}
+
+#[test]
+fn click_after_game_over_restarts_with_empty_field() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, PLAYER_START);
+    harness.step(PLAYER_START, false);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+
+    harness.click(PLAYER_START);
+
+    assert_eq!(harness.game.state(), GameState::Playing);
+    assert_eq!(harness.game.score(), 0);
+    assert_eq!(harness.game.enemy_count(), 0);
+}
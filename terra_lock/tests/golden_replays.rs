@@ -0,0 +1,78 @@
+// リプレイによる回帰テスト
+//
+// tests/replays/*.replay を再生し、記録された最終スコア・撃破数・状態ハッシュと比較する。
+// ゲームプレイの結果を意図して変更した場合は、以下で期待値を更新してコミットする:
+//   cargo run --release --bin replay -- update tests/replays/*.replay
+
+use std::path::Path;
+
+use macroquad::prelude::Vec2;
+use terra_lock::game::{self, InputFrame};
+use terra_lock::replay::Replay;
+
+#[test]
+fn golden_replays_match_recorded_outcomes() {
+    game::set_logging(false);
+
+    let directory = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/replays");
+    let mut paths: Vec<_> = std::fs::read_dir(&directory)
+        .expect("tests/replays directory")
+        .map(|entry| entry.expect("replay entry").path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "replay"))
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "no replay files in {}", directory.display());
+
+    let mut failures = Vec::new();
+    for path in &paths {
+        let name = path.file_name().unwrap().to_string_lossy();
+        let text = std::fs::read_to_string(path).expect("readable replay");
+        let replay = Replay::parse(&text).unwrap_or_else(|error| panic!("{}: {}", name, error));
+        let expected = replay
+            .expected
+            .as_ref()
+            .unwrap_or_else(|| panic!("{}: no expectations recorded", name));
+
+        if let Some(difference) = expected.describe_mismatch(&replay.play()) {
+            failures.push(format!("{}:\n  {}", name, difference.replace('\n', "\n  ")));
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "gameplay outcome changed for {} of {} replays:\n{}\n\n\
+         If this change is intentional, update the goldens with:\n  \
+         cargo run --release --bin replay -- update tests/replays/*.replay",
+        failures.len(),
+        paths.len(),
+        failures.join("\n")
+    );
+}
+
+#[test]
+fn bomb_presses_round_trip_through_the_replay_text() {
+    let frame = |left_button_down, bomb_button_down| InputFrame {
+        mouse_pos: Vec2::new(400.0, 500.0),
+        left_button_down,
+        bomb_button_down,
+    };
+    let replay = Replay {
+        seed: 1,
+        frames: vec![frame(false, false), frame(true, false), frame(false, true), frame(false, true)],
+        expected: None,
+    };
+
+    let text = replay.to_text();
+    // ボムを押していないフレームは従来の4列のまま
+    assert!(text.contains("\n1 400 500 1\n"));
+    assert!(text.contains("\n2 400 500 0 1\n"));
+    assert_eq!(Replay::parse(&text).expect("valid replay").frames, replay.frames);
+}
+
+#[test]
+fn replays_from_an_older_format_are_rejected_by_version() {
+    // version 1 の撃破数は4タイプ分（現在の形式では解析できない行より先にバージョンで拒否）
+    let text = "version 1\nseed 1\nexpect kills 1 2 3 4\nframes\n1 400 500 0\n";
+    let error = Replay::parse(text).expect_err("old replay should be rejected");
+    assert!(error.contains("unsupported replay version 1"), "{}", error);
+}
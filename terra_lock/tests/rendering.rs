@@ -0,0 +1,108 @@
+// 描画の結合テスト（グラフィックスコンテキストなしで各画面の描画命令を検証）
+
+mod common;
+
+use common::{Harness, PLAYER_START, SHIP_PANELS};
+use macroquad::prelude::{Vec2, YELLOW};
+use terra_lock::game::{EnemyType, GameState};
+use terra_lock::renderer::{DrawCommand, NullRenderer, SnapshotRenderer};
+
+fn snapshot(harness: &Harness) -> SnapshotRenderer {
+    let mut renderer = SnapshotRenderer::new(800.0, 600.0);
+    harness.game.draw(&mut renderer);
+    renderer
+}
+
+#[test]
+fn every_screen_draws_without_a_graphics_context() {
+    let mut harness = Harness::title();
+    let mut renderer = NullRenderer::default();
+
+    harness.game.draw(&mut renderer);
+    harness.click(PLAYER_START);
+    harness.game.draw(&mut renderer);
+    harness.click(SHIP_PANELS[0]);
+    harness.step(PLAYER_START, false);
+    harness.game.spawn_enemy(EnemyType::Straight, PLAYER_START, 0.0);
+    harness.game.draw(&mut renderer);
+    harness.step(PLAYER_START, false);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+    harness.game.draw(&mut renderer);
+    harness.game.draw_debug_info(&mut renderer, 60.0);
+
+    assert!(renderer.draw_calls > 0);
+}
+
+#[test]
+fn title_screen_shows_title_and_start_prompt() {
+    let harness = Harness::title();
+    let renderer = snapshot(&harness);
+
+    let texts: Vec<&str> = renderer.texts().collect();
+    assert!(texts.contains(&"TERRA LOCK"));
+    assert!(texts.contains(&"Click to Start"));
+}
+
+#[test]
+fn ship_select_screen_lists_every_ship_with_its_high_scores() {
+    let mut harness = Harness::title();
+    harness.click(PLAYER_START);
+    harness.step(SHIP_PANELS[1], false);
+    let renderer = snapshot(&harness);
+
+    let texts: Vec<&str> = renderer.texts().collect();
+    assert!(texts.contains(&"SELECT YOUR SHIP"));
+    for name in ["TERRA", "VULCAN", "SPECTER"] {
+        assert!(texts.contains(&name));
+    }
+    assert!(texts.contains(&"Shot: spread"));
+    assert!(texts.contains(&"Lock: growing x8"));
+    assert_eq!(texts.iter().filter(|&&text| text == "HIGH SCORES").count(), 3);
+
+    // マウスが乗っているパネルは黄色枠
+    let highlighted = renderer
+        .commands
+        .iter()
+        .filter(|command| matches!(command, DrawCommand::RectangleLines { color, .. } if *color == YELLOW))
+        .count();
+    assert_eq!(highlighted, 1);
+}
+
+#[test]
+fn hud_shows_score_and_lock_count_while_locking() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, Vec2::new(330.0, 460.0));
+    harness.spawn(EnemyType::Straight, Vec2::new(470.0, 460.0));
+    harness.hold(PLAYER_START, 0.4);
+
+    let renderer = snapshot(&harness);
+
+    let texts: Vec<&str> = renderer.texts().collect();
+    assert!(texts.contains(&"SCORE: 0"));
+    assert!(texts.contains(&"LOCK: 2/6"));
+    assert!(texts.contains(&"HEAT"));
+
+    // ロックオン中の敵機は黄色
+    let locked_circles = renderer
+        .commands
+        .iter()
+        .filter(|command| matches!(command, DrawCommand::Circle { radius, color, .. } if *radius == 10.0 && *color == YELLOW))
+        .count();
+    assert_eq!(locked_circles, 2);
+}
+
+#[test]
+fn game_over_screen_shows_final_score_and_stats() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, PLAYER_START);
+    harness.step(PLAYER_START, false);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+
+    let renderer = snapshot(&harness);
+
+    let texts: Vec<&str> = renderer.texts().collect();
+    assert!(texts.contains(&"GAME OVER"));
+    assert!(texts.contains(&"FINAL SCORE: 0"));
+    assert!(texts.contains(&"SURVIVAL"));
+    assert!(renderer.to_svg().contains(">arc: 0  homing: 0</text>"));
+}
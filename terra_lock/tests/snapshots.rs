@@ -0,0 +1,156 @@
+// 見た目のスナップショットテスト
+//
+// 固定した状態の各画面をソフトウェアラスタライザで描画し、tests/snapshots/*.png と比較する。
+// 文字のセンタリング等レイアウトの崩れを、GPUのない環境でも検出する。
+// 描画を意図して変更した場合は、以下で参照画像を更新してコミットする:
+//   UPDATE_SNAPSHOTS=1 cargo test --test snapshots
+// 不一致の場合は target/snapshots/ に実際の画像と差分画像（不一致箇所が赤）を出力する。
+
+mod common;
+
+use std::path::{Path, PathBuf};
+
+use common::{Harness, PLAYER_START, SHIP_PANELS};
+use macroquad::prelude::{Vec2, BLACK};
+use terra_lock::game::{EnemyType, GameState};
+use terra_lock::raster::{RasterImage, RasterRenderer};
+use terra_lock::renderer::Renderer;
+
+const WIDTH: u32 = 800;
+const HEIGHT: u32 = 600;
+
+// チャンネルごとの許容差と、許容差を超えてよいピクセルの割合
+const CHANNEL_TOLERANCE: u8 = 8;
+const MAX_DIFFERING_RATIO: f64 = 0.001;
+
+fn render(harness: &Harness) -> RasterImage {
+    let mut renderer = RasterRenderer::new(WIDTH, HEIGHT);
+    renderer.clear_background(BLACK);
+    harness.game.draw(&mut renderer);
+    renderer.image
+}
+
+fn assert_snapshot(name: &str, actual: &RasterImage) {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let reference_path = manifest_dir.join("tests/snapshots").join(format!("{}.png", name));
+
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&reference_path, actual.to_png().expect("encode PNG")).expect("write reference");
+        return;
+    }
+
+    let data = std::fs::read(&reference_path).unwrap_or_else(|error| {
+        panic!("{}: {} (run with UPDATE_SNAPSHOTS=1 to create it)", reference_path.display(), error)
+    });
+    let reference = RasterImage::from_png(&data).unwrap_or_else(|error| panic!("{}: {}", reference_path.display(), error));
+
+    let diff = reference.compare(actual, CHANNEL_TOLERANCE);
+    let max_differing = (WIDTH as f64 * HEIGHT as f64 * MAX_DIFFERING_RATIO) as usize;
+    if diff.differing_pixels > max_differing {
+        let output_dir = failure_output_dir(manifest_dir);
+        std::fs::create_dir_all(&output_dir).expect("create output directory");
+        let actual_path = output_dir.join(format!("{}.actual.png", name));
+        let diff_path = output_dir.join(format!("{}.diff.png", name));
+        std::fs::write(&actual_path, actual.to_png().expect("encode PNG")).expect("write actual image");
+        std::fs::write(&diff_path, reference.diff_image(actual, CHANNEL_TOLERANCE).to_png().expect("encode PNG"))
+            .expect("write diff image");
+
+        panic!(
+            "{}: {} pixels differ (allowed {}, max channel delta {})\n  actual: {}\n  diff:   {}\n\n\
+             If this change is intentional, update the references with:\n  \
+             UPDATE_SNAPSHOTS=1 cargo test --test snapshots",
+            name,
+            diff.differing_pixels,
+            max_differing,
+            diff.max_delta,
+            actual_path.display(),
+            diff_path.display()
+        );
+    }
+}
+
+fn failure_output_dir(manifest_dir: &Path) -> PathBuf {
+    std::env::var_os("CARGO_TARGET_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir.join("target"))
+        .join("snapshots")
+}
+
+#[test]
+fn title_screen() {
+    let harness = Harness::title();
+
+    assert_snapshot("title", &render(&harness));
+}
+
+#[test]
+fn ship_select_screen() {
+    let mut harness = Harness::title();
+    harness.click(PLAYER_START);
+    harness.step(SHIP_PANELS[1], false);
+    assert_eq!(harness.game.state(), GameState::ShipSelect);
+
+    assert_snapshot("ship_select", &render(&harness));
+}
+
+#[test]
+fn playing_screen_with_locks_homing_lasers_and_bonus() {
+    let mut harness = Harness::playing();
+
+    // 2機同時撃破でボーナス表示
+    harness.spawn(EnemyType::Straight, Vec2::new(330.0, 460.0));
+    harness.spawn(EnemyType::Straight, Vec2::new(470.0, 460.0));
+    harness.hold(PLAYER_START, 0.4);
+    harness.release();
+    harness.wait(0.6);
+    assert_eq!(harness.game.bonus_displays().len(), 1);
+
+    // 画面上部の敵機をロックオンし、画面下端へ移動してから発射（長い飛行距離）
+    let aim = Vec2::new(400.0, 200.0);
+    harness.spawn(EnemyType::Zigzag, Vec2::new(340.0, 150.0));
+    harness.spawn(EnemyType::Homing, Vec2::new(460.0, 150.0));
+    harness.hold(aim, 0.4);
+    harness.step(Vec2::new(400.0, 560.0), false);
+
+    // レーザーの飛行中に別の敵機をロックオン（1機はロックオン進捗の途中）
+    harness.spawn(EnemyType::Arc, Vec2::new(560.0, 380.0));
+    harness.spawn(EnemyType::Straight, Vec2::new(640.0, 360.0));
+    harness.hold(Vec2::new(600.0, 440.0), 0.3);
+    harness.spawn(EnemyType::Straight, Vec2::new(660.0, 480.0));
+    harness.hold(Vec2::new(600.0, 440.0), 0.1);
+    assert_eq!(harness.game.locked_count(), 2);
+    assert_eq!(harness.game.homing_lasers().len(), 2);
+    assert_eq!(harness.game.bonus_displays().len(), 1);
+
+    assert_snapshot("playing", &render(&harness));
+}
+
+#[test]
+fn playing_screen_with_every_enemy_type_and_bullets() {
+    let mut harness = Harness::playing();
+
+    // 射撃型を停止させて敵弾を撃たせる
+    harness.spawn_moving(EnemyType::Shooter, Vec2::new(700.0, 100.0), 120.0);
+    harness.wait(1.2);
+    assert!(!harness.game.enemy_bullets().is_empty());
+
+    for (i, &enemy_type) in EnemyType::ALL.iter().filter(|&&enemy_type| enemy_type != EnemyType::Shooter).enumerate() {
+        harness.spawn(enemy_type, Vec2::new(80.0 + i as f32 * 70.0, 300.0));
+    }
+    harness.step(PLAYER_START, false);
+
+    assert_snapshot("enemy_types", &render(&harness));
+}
+
+#[test]
+fn game_over_screen() {
+    let mut harness = Harness::playing();
+    harness.spawn(EnemyType::Straight, Vec2::new(400.0, 300.0));
+    harness.click(PLAYER_START);
+    harness.wait(1.0);
+    harness.spawn(EnemyType::Straight, PLAYER_START);
+    harness.step(PLAYER_START, false);
+    assert_eq!(harness.game.state(), GameState::GameOver);
+
+    assert_snapshot("game_over", &render(&harness));
+}